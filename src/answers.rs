@@ -0,0 +1,239 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::input;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Known answers for a day, as committed in `src/y<year>/answers/NN.toml`:
+///
+/// ```toml
+/// part_one = 24000
+/// part_two = "MCD"
+/// ```
+///
+/// Multi-line answers, like a [`Bitmap`](crate::bitmap::Bitmap), use TOML's multi-line strings:
+///
+/// ```toml
+/// part_two = '''
+/// #..#
+/// ####
+/// '''
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+pub fn path(year: i16, day: u8) -> PathBuf {
+    input::year_dir(year)
+        .join("answers")
+        .join(format!("{:02}.toml", day))
+}
+
+/// The answers for one of a day's [named inputs](crate::input::named_inputs):
+/// `answers/NN/<name>.toml`.
+pub fn named_path(year: i16, day: u8, name: &str) -> PathBuf {
+    path(year, day)
+        .with_extension("")
+        .join(format!("{}.toml", name))
+}
+
+/// Loads the known answers for a day. A missing file means that no answers are known yet.
+pub fn load(year: i16, day: u8) -> Result<Answers, String> {
+    load_path(&path(year, day))
+}
+
+/// Loads answers from `path`. Any error other than a missing file is returned.
+pub fn load_path(path: &Path) -> Result<Answers, String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => parse(&contents).map_err(|e| format!("{}: {}", path.display(), e)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+        Err(e) => Err(format!("Failed to read \"{}\": {}", path.display(), e)),
+    }
+}
+
+/// Parses the small subset of TOML that answer files use:
+/// `key = value` lines where the value is an integer, a double-quoted string
+/// or a multi-line string in `"""` or `'''`, and `#` comments.
+pub fn parse(contents: &str) -> Result<Answers, String> {
+    let mut answers = Answers::default();
+    let mut lines = contents.lines().enumerate();
+
+    while let Some((i, line)) = lines.next() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("line {}: expected `key = value`", i + 1))?;
+        let value = value.trim();
+        let value = match ["\"\"\"", "'''"]
+            .into_iter()
+            .find(|delimiter| value.starts_with(delimiter))
+        {
+            Some(delimiter) => parse_multi_line(&value[3..], delimiter, &mut lines),
+            None => parse_value(value),
+        }
+        .map_err(|e| format!("line {}: {}", i + 1, e))?;
+
+        match key.trim() {
+            "part_one" => answers.part_one = Some(value),
+            "part_two" => answers.part_two = Some(value),
+            key => return Err(format!("line {}: unknown key \"{}\"", i + 1, key)),
+        }
+    }
+
+    Ok(answers)
+}
+
+/// Collects the lines of a multi-line string up to its closing `delimiter`.
+/// Like in TOML, a newline right after the opening delimiter is not part of the string.
+fn parse_multi_line<'a>(
+    first: &str,
+    delimiter: &str,
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+) -> Result<String, String> {
+    let mut raw = String::from(first);
+    let mut rest = first;
+    loop {
+        if let Some(end) = rest.find(delimiter) {
+            let after = rest[end + delimiter.len()..].trim();
+            if !after.is_empty() && !after.starts_with('#') {
+                return Err(format!("unexpected \"{}\" after string", after));
+            }
+            raw.truncate(raw.len() - rest.len() + end);
+            break;
+        }
+        match lines.next() {
+            Some((_, line)) => {
+                raw.push('\n');
+                raw.push_str(line);
+                rest = line;
+            }
+            None => return Err("unterminated string".into()),
+        }
+    }
+
+    let raw = raw.strip_prefix('\n').unwrap_or(&raw);
+    if delimiter == "'''" {
+        Ok(raw.to_string())
+    } else {
+        unescape(raw)
+    }
+}
+
+fn unescape(value: &str) -> Result<String, String> {
+    let mut out = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('n') => out.push('\n'),
+                Some('t') => out.push('\t'),
+                Some('"') => out.push('"'),
+                Some('\\') => out.push('\\'),
+                other => return Err(format!("unsupported escape sequence \\{:?}", other)),
+            },
+            c => out.push(c),
+        }
+    }
+    Ok(out)
+}
+
+fn parse_value(value: &str) -> Result<String, String> {
+    if let Some(quoted) = value.strip_prefix('"') {
+        // find the closing quote, skipping escaped characters.
+        let mut chars = quoted.char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    let rest = quoted[i + 1..].trim();
+                    return if rest.is_empty() || rest.starts_with('#') {
+                        unescape(&quoted[..i])
+                    } else {
+                        Err(format!("unexpected \"{}\" after string", rest))
+                    };
+                }
+                '\\' => {
+                    chars.next();
+                }
+                _ => (),
+            }
+        }
+        Err("unterminated string".into())
+    } else {
+        let value = value.split('#').next().unwrap().trim();
+        match value.parse::<i64>() {
+            Ok(n) => Ok(n.to_string()),
+            Err(_) => Err(format!(
+                "expected an integer or a string, found \"{}\"",
+                value
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = parse("# day 5\npart_one = \"CMZ\"\n\npart_two = 12 # verified\n").unwrap();
+        assert_eq!(answers.part(1), Some("CMZ"));
+        assert_eq!(answers.part(2), Some("12"));
+    }
+
+    #[test]
+    fn test_parse_escapes() {
+        let answers = parse(r#"part_two = "a\"b\\c\n""#).unwrap();
+        assert_eq!(answers.part_one, None);
+        assert_eq!(answers.part_two.as_deref(), Some("a\"b\\c\n"));
+    }
+
+    #[test]
+    fn test_parse_multi_line() {
+        let answers =
+            parse("part_one = '''\n#..#\n####\n'''\npart_two = \"\"\"a\\tb\nc\"\"\" # ok\n")
+                .unwrap();
+        assert_eq!(answers.part(1), Some("#..#\n####\n"));
+        assert_eq!(answers.part(2), Some("a\tb\nc"));
+
+        assert!(parse("part_one = '''\n#..#\n").is_err());
+    }
+
+    #[test]
+    fn test_load_path() {
+        let missing = std::env::temp_dir().join("aoc_answers_test_missing.toml");
+        assert_eq!(load_path(&missing), Ok(Answers::default()));
+
+        let dir = std::env::temp_dir().join("aoc_answers_test_dir");
+        fs::create_dir_all(&dir).unwrap();
+        assert!(load_path(&dir).is_err());
+        fs::remove_dir(&dir).ok();
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("part_one 5").is_err());
+        assert!(parse("part_three = 5").is_err());
+        assert!(parse("part_one = \"open").is_err());
+        assert!(parse("part_one = CMZ").is_err());
+    }
+}
@@ -0,0 +1,317 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The session cookie. Takes precedence over the session files.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Overrides the website's address, e.g. to point downloads at a local server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// The year commands use when no `--year` is given.
+pub const YEAR_VAR: &str = "AOC_YEAR";
+
+// the website's operator asks automated tools to identify themselves.
+const USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/fspoettel/advent-of-code-rust)"
+);
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// No session cookie in the environment or any of the session files.
+    MissingSession {
+        tried: Vec<PathBuf>,
+    },
+    /// The website answered 400 or 401, which it does for invalid or expired sessions.
+    Unauthorized,
+    /// The website answered 404, which it does for puzzles that are not unlocked yet.
+    NotFound {
+        url: String,
+    },
+    Status {
+        url: String,
+        status: u16,
+    },
+    Transport(String),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::MissingSession { tried } => {
+                write!(
+                    f,
+                    "no session cookie found. Set {} or paste it into one of:",
+                    SESSION_VAR
+                )?;
+                for path in tried {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                Ok(())
+            }
+            ClientError::Unauthorized => {
+                write!(f, "the session cookie is invalid or has expired")
+            }
+            ClientError::NotFound { url } => {
+                write!(f, "{} was not found, is the puzzle unlocked yet?", url)
+            }
+            ClientError::Status { url, status } => {
+                write!(f, "{} responded with status {}", url, status)
+            }
+            ClientError::Transport(message) => write!(f, "request failed: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+/// A minimal client for the Advent of Code website.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Reads the base url from `$AOC_BASE_URL` and the session as described in [`session_files`].
+    pub fn from_env() -> Result<Client, ClientError> {
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let session = load_session(env::var(SESSION_VAR).ok(), &session_files())?;
+        Ok(Client::new(&base_url, &session))
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn get(&self, path: &str) -> Result<String, ClientError> {
+        let url = format!("{}{}", self.base_url, path);
+        let request = self.agent.get(&url).set("Cookie", &self.cookie());
+        read_response(&url, request.call())
+    }
+
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, ClientError> {
+        let url = format!("{}{}", self.base_url, path);
+        let request = self.agent.post(&url).set("Cookie", &self.cookie());
+        read_response(&url, request.send_form(form))
+    }
+
+    pub fn input(&self, year: i16, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, ClientError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| ClientError::Transport(e.to_string())),
+        Err(ureq::Error::Status(400 | 401, _)) => Err(ClientError::Unauthorized),
+        Err(ureq::Error::Status(404, _)) => Err(ClientError::NotFound { url: url.into() }),
+        Err(ureq::Error::Status(status, _)) => Err(ClientError::Status {
+            url: url.into(),
+            status,
+        }),
+        Err(ureq::Error::Transport(transport)) => {
+            Err(ClientError::Transport(transport.to_string()))
+        }
+    }
+}
+
+/// Files the session cookie is read from if `$AOC_SESSION` is not set, in order:
+/// `~/.adventofcode.session` (shared with aoc-cli) and `~/.config/adventofcode.session`.
+pub fn session_files() -> Vec<PathBuf> {
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from);
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| home.as_ref().map(|home| home.join(".config")));
+
+    home.map(|home| home.join(".adventofcode.session"))
+        .into_iter()
+        .chain(config.map(|config| config.join("adventofcode.session")))
+        .collect()
+}
+
+fn load_session(from_env: Option<String>, files: &[PathBuf]) -> Result<String, ClientError> {
+    from_env
+        .into_iter()
+        .chain(
+            files
+                .iter()
+                .filter_map(|path| fs::read_to_string(path).ok()),
+        )
+        .map(|session| session.trim().to_string())
+        .find(|session| !session.is_empty())
+        .ok_or_else(|| ClientError::MissingSession {
+            tried: files.to_vec(),
+        })
+}
+
+/// The year of the most recent event. Puzzles unlock at midnight EST (UTC-5).
+pub fn latest_year() -> i16 {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs() as i64);
+    let (year, month) = year_month((seconds - 5 * 3600).div_euclid(86400));
+    if month == 12 {
+        year
+    } else {
+        year - 1
+    }
+}
+
+/// The year commands default to: `$AOC_YEAR` if set, otherwise the year of the most recent event.
+pub fn default_year() -> i16 {
+    env::var(YEAR_VAR)
+        .ok()
+        .and_then(|year| year.trim().parse().ok())
+        .unwrap_or_else(latest_year)
+}
+
+/// Converts days since 1970-01-01 to a (year, month) date.
+/// See <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+fn year_month(days: i64) -> (i16, u8) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year as i16, month as u8)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Answers a single request with `status` and `body`.
+    /// Returns the base url to send the request to and a handle to the raw request.
+    pub(crate) fn serve_once(status: u16, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let body = body.to_string();
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = length.trim().parse().unwrap();
+                }
+                request += &line;
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+            }
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request += &String::from_utf8(content).unwrap();
+
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_input() {
+        let (base_url, server) = serve_once(200, "1\n2\n");
+        let client = Client::new(&base_url, "abc\n");
+        assert_eq!(client.input(2022, 1).unwrap(), "1\n2\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc\r\n"));
+        assert!(request.contains(&format!("User-Agent: {}\r\n", USER_AGENT)));
+    }
+
+    #[test]
+    fn test_input_errors() {
+        let (base_url, _) = serve_once(404, "Please don't repeatedly request this endpoint.");
+        let client = Client::new(&base_url, "abc");
+        assert!(matches!(
+            client.input(2022, 25),
+            Err(ClientError::NotFound { .. })
+        ));
+
+        let (base_url, _) = serve_once(400, "Puzzle inputs differ by user.");
+        let client = Client::new(&base_url, "abc");
+        assert!(matches!(
+            client.input(2022, 1),
+            Err(ClientError::Unauthorized)
+        ));
+    }
+
+    #[test]
+    fn test_load_session() {
+        let path = env::temp_dir().join("aoc_session_test");
+        fs::write(&path, "from_file\n").unwrap();
+        let missing = env::temp_dir().join("aoc_session_test_missing");
+
+        let files = [missing.clone(), path.clone()];
+        assert_eq!(load_session(None, &files).unwrap(), "from_file");
+        assert_eq!(
+            load_session(Some("from_env".into()), &files).unwrap(),
+            "from_env"
+        );
+        assert!(matches!(
+            load_session(Some(" ".into()), &[missing]),
+            Err(ClientError::MissingSession { .. })
+        ));
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_year_month() {
+        assert_eq!(year_month(0), (1970, 1));
+        // 2022-12-01
+        assert_eq!(year_month(19327), (2022, 12));
+        // 2023-11-30
+        assert_eq!(year_month(19691), (2023, 11));
+    }
+}
@@ -0,0 +1,12 @@
+use advent_of_code::y2022::day08::{part_one, part_two};
+
+fn main() {
+    let options = advent_of_code::runner::Options::from_env();
+    if options.all_inputs {
+        advent_of_code::runner::run_all_inputs(2022, 8, &options);
+    }
+
+    let input = &advent_of_code::read_input(2022, 8);
+    advent_of_code::solve!(2022, 8, 1, part_one, input);
+    advent_of_code::solve!(2022, 8, 2, part_two, input);
+}
@@ -0,0 +1,61 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::aoc::{self, Client};
+use advent_of_code::{input, puzzle};
+use std::fs;
+use std::process;
+
+struct Args {
+    day: u8,
+    year: Option<i16>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        day: args.free_from_str()?,
+        year: args.opt_value_from_str(["-y", "--year"])?,
+    })
+}
+
+fn exit_with_error(message: impl std::fmt::Display) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn main() {
+    let args = parse_args()
+        .unwrap_or_else(|e| exit_with_error(format!("Failed to process arguments: {}", e)));
+    let year = args.year.unwrap_or_else(aoc::default_year);
+    let client = Client::from_env().unwrap_or_else(|e| exit_with_error(e));
+
+    println!("Fetching puzzle for day {}, {}...", args.day, year);
+    let page = puzzle::fetch(&client, year, args.day)
+        .unwrap_or_else(|e| exit_with_error(format!("Failed to fetch puzzle: {}", e)));
+    let parts = puzzle::parts(&page, &puzzle::url(&client, year, args.day));
+    if parts.is_empty() {
+        exit_with_error("Could not find the puzzle description on the page.");
+    }
+
+    let path = puzzle::path(year, args.day);
+    let existing = fs::read_to_string(&path).ok();
+    match puzzle::update(existing.as_deref(), &parts) {
+        Some(contents) => {
+            input::write_atomic(&path, &contents)
+                .unwrap_or_else(|e| exit_with_error(format!("Failed to write puzzle file: {}", e)));
+            let new_parts: Vec<String> = (puzzle::known_parts(existing.as_deref().unwrap_or(""))
+                ..parts.len())
+                .map(|part| (part + 1).to_string())
+                .collect();
+            println!("---");
+            println!(
+                "🎄 Wrote part {} to \"{}\".",
+                new_parts.join(" and "),
+                path.display()
+            );
+        }
+        None => println!("\"{}\" is up to date.", path.display()),
+    }
+}
@@ -2,47 +2,133 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::aoc::{self, Client};
+use advent_of_code::puzzle::{self, Example};
+use advent_of_code::template;
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::PathBuf,
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
+const MODULE_TEMPLATE: &str = r###"pub fn part_one(_input: &str) -> Option<{{part_one_type}}> {
     None
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(_input: &str) -> Option<{{part_two_type}}> {
     None
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+        let input = crate::read_file("examples", {{year}}, {{day}});
+        assert_eq!(part_one(&input), {{part_one_expected}});
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+        let input = crate::read_file("examples", {{year}}, {{day}});
+        assert_eq!(part_two(&input), {{part_two_expected}});
     }
 }
 "###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+const BIN_TEMPLATE: &str = r###"use advent_of_code::y{{year}}::day{{day_padded}}::{part_one, part_two};
+
+fn main() {
+    let options = advent_of_code::runner::Options::from_env();
+    if options.all_inputs {
+        advent_of_code::runner::run_all_inputs({{year}}, {{day}}, &options);
+    }
+
+    let input = &advent_of_code::read_input({{year}}, {{day}});
+    advent_of_code::solve!({{year}}, {{day}}, 1, part_one, input);
+    advent_of_code::solve!({{year}}, {{day}}, 2, part_two, input);
+}
+"###;
+
+const YEAR_TEMPLATE: &str = r###"/*
+ * Solutions for {{year}}.
+ * `cargo scaffold` declares new days below and adds them to the `days!` list.
+ */
+crate::days! {
+}
+"###;
+
+const YEARS_PATH: &str = "src/lib.rs";
+
+/// Folders next to a year's solutions that hold its data files.
+const DATA_FOLDERS: [&str; 4] = ["inputs", "examples", "answers", "puzzles"];
+
+struct Args {
+    day: u8,
+    html: Option<String>,
+    download: bool,
+    year: Option<i16>,
+    template: Option<String>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    Ok(Args {
+        html: args.opt_value_from_str("--html")?,
+        download: args.contains("--download"),
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        template: args.opt_value_from_str("--template")?,
+        day: args.free_from_str()?,
+    })
+}
+
+/// Reads the puzzle page from `--html <path>` or downloads it with `--download`.
+fn load_page(args: &Args, year: i16) -> Result<Option<String>, String> {
+    if let Some(path) = &args.html {
+        return fs::read_to_string(path)
+            .map(Some)
+            .map_err(|e| format!("Failed to read \"{}\": {}", path, e));
+    }
+    if !args.download {
+        return Ok(None);
+    }
+
+    let client = Client::from_env().map_err(|e| e.to_string())?;
+    puzzle::fetch(&client, year, args.day)
+        .map(Some)
+        .map_err(|e| format!("Failed to download puzzle: {}", e))
+}
+
+/// The answer type of a part and the expected value in its test, e.g. `("u32", "Some(24000)")`.
+fn expected_answer(answer: Option<&String>) -> (&'static str, String) {
+    match answer {
+        None => ("u32", "None".into()),
+        Some(answer) if answer.parse::<u32>().is_ok() => ("u32", format!("Some({})", answer)),
+        Some(answer) if answer.parse::<u64>().is_ok() => ("u64", format!("Some({})", answer)),
+        Some(answer) if answer.parse::<i64>().is_ok() => ("i64", format!("Some({})", answer)),
+        Some(answer) => ("String", format!("Some({:?}.to_string())", answer)),
+    }
+}
+
+/// The values of the placeholders that templates can use, e.g. `{{day_padded}}`.
+fn placeholders(year: i16, day: u8, example: &Example) -> Vec<(&'static str, String)> {
+    let answer = |part: usize| example.answers.get(part).and_then(Option::as_ref);
+    let (part_one_type, part_one_expected) = expected_answer(answer(0));
+    let (part_two_type, part_two_expected) = expected_answer(answer(1));
+
+    vec![
+        ("year", year.to_string()),
+        ("day", day.to_string()),
+        ("day_padded", format!("{:02}", day)),
+        ("part_one_type", part_one_type.into()),
+        ("part_one_expected", part_one_expected),
+        ("part_one_answer", answer(0).cloned().unwrap_or_default()),
+        ("part_two_type", part_two_type.into()),
+        ("part_two_expected", part_two_expected),
+        ("part_two_answer", answer(1).cloned().unwrap_or_default()),
+    ]
 }
 
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
@@ -50,23 +136,143 @@ fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+/// Appends `entry` to the list of the `list!` macro in the file at `path`,
+/// e.g. `1 => day01,` to the `days!` list in a year's `mod.rs`.
+fn register(path: &str, list: &str, entry: &str) -> Result<(), std::io::Error> {
+    let mut contents = fs::read_to_string(path)?;
+    let list_end = contents
+        .find(&format!("{}! {{", list))
+        .and_then(|start| contents[start..].find("\n}").map(|end| start + end))
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("could not find `{}!` list", list),
+            )
+        })?;
+
+    contents.insert_str(list_end, &format!("\n    {}", entry));
+    fs::write(path, contents)
+}
+
+/// Declares `pub mod <module>;` in the file at `path`, after the last module declared there,
+/// or above the `list!` macro if there is none yet.
+fn declare(path: &str, list: &str, module: &str) -> Result<(), std::io::Error> {
+    let mut contents = fs::read_to_string(path)?;
+    let declaration = format!("pub mod {};\n", module);
+    let last_module = contents.rfind("\npub mod ").and_then(|start| {
+        contents[start + 1..]
+            .find('\n')
+            .map(|end| start + 1 + end + 1)
+    });
+
+    match last_module {
+        Some(position) => contents.insert_str(position, &declaration),
+        None => {
+            let position = contents
+                .find(&format!("crate::{}! {{", list))
+                .ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        format!("could not find `{}!` list", list),
+                    )
+                })?;
+            contents.insert_str(position, &format!("{}\n", declaration));
+        }
+    }
+    fs::write(path, contents)
+}
+
+/// Renders the module template from `.aoc` (see [`template::path`]), or the built-in one.
+/// Returns the module and the path of the template that was used.
+fn render_module(
+    name: Option<&str>,
+    values: &[(&str, String)],
+) -> Result<(String, Option<PathBuf>), String> {
+    match template::load(name)? {
+        Some(source) => {
+            let path = template::path(name);
+            template::render(&source, values)
+                .map(|module| (module, Some(path.clone())))
+                .map_err(|e| format!("{}: {}", path.display(), e))
+        }
+        None => Ok((render_builtin(MODULE_TEMPLATE, values), None)),
+    }
+}
+
+fn render_builtin(template: &str, values: &[(&str, String)]) -> String {
+    template::render(template, values).expect("built-in templates only use known placeholders")
+}
+
+/// Creates the folders of a year that has no solutions yet, declares it and adds it to the `years!` list.
+fn create_year(year: i16, year_dir: &str, module: &str) -> Result<(), std::io::Error> {
+    for folder in DATA_FOLDERS {
+        fs::create_dir_all(format!("{}/{}", year_dir, folder))?;
+    }
+    safe_create_file(&format!("{}/mod.rs", year_dir))?.write_all(module.as_bytes())?;
+    declare(YEARS_PATH, "years", &format!("y{}", year))?;
+    register(YEARS_PATH, "years", &format!("{} => y{},", year, year))
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let args = match parse_args() {
+        Ok(args) => args,
         Err(_) => {
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
         }
     };
+    let day = args.day;
+    let year = args.year.unwrap_or_else(aoc::default_year);
+
+    let example = match load_page(&args, year) {
+        Ok(Some(page)) => puzzle::extract_example(&page),
+        Ok(None) => Example::default(),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let values = placeholders(year, day, &example);
+    let (module, template_path) = match render_module(args.template.as_deref(), &values) {
+        Ok(module) => module,
+        Err(e) => {
+            eprintln!("Failed to render template: {}", e);
+            process::exit(1);
+        }
+    };
 
     let day_padded = format!("{:02}", day);
+    let year_dir = format!("src/y{}", year);
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let input_path = format!("{}/inputs/{}.txt", year_dir, day_padded);
+    let example_path = format!("{}/examples/{}.txt", year_dir, day_padded);
+    let module_path = format!("{}/day{}.rs", year_dir, day_padded);
+    let year_module_path = format!("{}/mod.rs", year_dir);
+    let bin_name = format!("{}-{}", year, day_padded);
+    let bin_path = format!("src/bin/{}.rs", bin_name);
+
+    if fs::metadata(&year_module_path).is_err() {
+        match create_year(year, &year_dir, &render_builtin(YEAR_TEMPLATE, &values)) {
+            Ok(_) => {
+                println!(
+                    "Created \"{}\" and registered {} in \"{}\"",
+                    &year_dir, year, YEARS_PATH
+                );
+            }
+            Err(e) => {
+                eprintln!("Failed to create year {}: {}", year, e);
+                process::exit(1);
+            }
+        }
+    }
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -76,39 +282,104 @@ fn main() {
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    match file.write_all(module.as_bytes()) {
+        Ok(_) => match template_path {
+            Some(path) => println!(
+                "Created module file \"{}\" from template \"{}\"",
+                &module_path,
+                path.display()
+            ),
+            None => println!("Created module file \"{}\"", &module_path),
+        },
+        Err(e) => {
+            eprintln!("Failed to write module contents: {}", e);
+            process::exit(1);
+        }
+    }
+
+    let mut file = match safe_create_file(&bin_path) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("Failed to create binary file: {}", e);
+            process::exit(1);
+        }
+    };
+
+    match file.write_all(render_builtin(BIN_TEMPLATE, &values).as_bytes()) {
         Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created binary file \"{}\"", &bin_path);
         }
         Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
+            eprintln!("Failed to write binary contents: {}", e);
             process::exit(1);
         }
     }
 
-    match create_file(&input_path) {
+    let day_module = format!("day{}", day_padded);
+    match declare(&year_module_path, "days", &day_module).and_then(|_| {
+        register(
+            &year_module_path,
+            "days",
+            &format!("{} => {},", day, day_module),
+        )
+    }) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Registered day in \"{}\"", &year_module_path);
         }
         Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
+            eprintln!("Failed to register day: {}", e);
             process::exit(1);
         }
     }
 
-    match create_file(&example_path) {
+    match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty input file \"{}\"", &input_path);
         }
         Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
+            eprintln!("Failed to create input file: {}", e);
             process::exit(1);
         }
     }
 
+    let existing_example = fs::read_to_string(&example_path).unwrap_or_default();
+    match &example.input {
+        Some(_) if !existing_example.trim().is_empty() => {
+            eprintln!(
+                "Warning: kept existing example file \"{}\", the example from the puzzle page was not written",
+                &example_path
+            );
+        }
+        Some(input) => match fs::write(&example_path, input) {
+            Ok(_) => {
+                println!(
+                    "Created example file \"{}\" from the puzzle page",
+                    &example_path
+                );
+            }
+            Err(e) => {
+                eprintln!("Failed to write example file: {}", e);
+                process::exit(1);
+            }
+        },
+        None => match create_file(&example_path) {
+            Ok(_) => {
+                println!("Created empty example file \"{}\"", &example_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create example file: {}", e);
+                process::exit(1);
+            }
+        },
+    }
+
+    for warning in &example.warnings {
+        eprintln!(
+            "Warning: {}, please check the example and the tests.",
+            warning
+        );
+    }
+
     println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
-    );
+    println!("🎄 Type `cargo solve {}` to run your solution.", &bin_name);
 }
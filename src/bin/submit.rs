@@ -0,0 +1,118 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::aoc::{self, Client};
+use advent_of_code::submit::{self, Attempt, Attempts, Outcome, WaitUntil};
+use advent_of_code::{input, registry};
+use std::process;
+use std::time::SystemTime;
+
+struct Args {
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    year: Option<i16>,
+    force: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        force: args.contains("--force"),
+        day: args.free_from_str()?,
+        part: args.free_from_str()?,
+        answer: args.opt_free_from_str()?,
+    })
+}
+
+fn exit_with_error(message: impl std::fmt::Display) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+/// Runs the registered solution for a part on the day's input.
+fn solve(year: i16, day: u8, part: u8) -> Result<String, String> {
+    let solution = registry::get(year, day)
+        .ok_or_else(|| format!("day {} of {} is not registered", day, year))?;
+    let input = input::read_path(&input::input_path(year, day), Some((year, day)))
+        .map_err(|e| e.to_string())?;
+    let solver = match part {
+        1 => solution.part_one,
+        _ => solution.part_two,
+    };
+
+    let (answer, _) = solver(&input);
+    answer.ok_or_else(|| format!("part {} of day {} is not solved", part, day))
+}
+
+fn main() {
+    let args = parse_args()
+        .unwrap_or_else(|e| exit_with_error(format!("Failed to process arguments: {}", e)));
+    if !(1..=2).contains(&args.part) {
+        exit_with_error(format!("Part must be 1 or 2, not {}.", args.part));
+    }
+
+    let year = args.year.unwrap_or_else(aoc::default_year);
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => {
+            let answer = solve(year, args.day, args.part).unwrap_or_else(|e| exit_with_error(e));
+            println!("Day {} part {} answer: {}", args.day, args.part, answer);
+            answer
+        }
+    };
+    let answer = answer.trim().to_string();
+    if answer.is_empty() || answer.contains('\n') || answer.contains('\t') {
+        exit_with_error(format!("{:?} is not a submittable answer.", answer));
+    }
+
+    let mut attempts = Attempts::load(year, args.day).unwrap_or_else(|e| exit_with_error(e));
+
+    if let Some(attempt) = attempts.find(args.part, &answer) {
+        match attempt.outcome {
+            Outcome::Correct => println!("{} was already submitted and is correct.", answer),
+            ref outcome => {
+                exit_with_error(format!("{} was already submitted: {}", answer, outcome))
+            }
+        }
+        return;
+    }
+    if let Some(violation) = attempts.bound_violation(args.part, &answer) {
+        eprintln!("Warning: {}.", violation);
+        if !args.force {
+            exit_with_error("Not submitting. Append `--force` to submit anyway.");
+        }
+    }
+
+    submit::check_wait(&WaitUntil::path(), SystemTime::now())
+        .unwrap_or_else(|e| exit_with_error(format!("Not submitting. {}", e)));
+
+    let client = Client::from_env().unwrap_or_else(|e| exit_with_error(e));
+    println!(
+        "Submitting {} for day {} part {}, {}...",
+        answer, args.day, args.part, year
+    );
+    let outcome = submit::submit(&client, year, args.day, args.part, &answer)
+        .unwrap_or_else(|e| exit_with_error(format!("Failed to submit answer: {}", e)));
+
+    let attempt = Attempt {
+        part: args.part,
+        answer,
+        outcome: outcome.clone(),
+    };
+    if let Err(e) = attempts.record(year, args.day, attempt) {
+        eprintln!("Failed to record attempt: {}", e);
+    }
+    if let Outcome::Wait(duration) = outcome {
+        if let Err(e) = WaitUntil(SystemTime::now() + duration).save_path(&WaitUntil::path()) {
+            eprintln!("Failed to record wait: {}", e);
+        }
+    }
+
+    match outcome {
+        Outcome::Correct | Outcome::AlreadySolved => println!("🎄 {}", outcome),
+        outcome => exit_with_error(outcome),
+    }
+}
@@ -0,0 +1,148 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt;
+use std::str::FromStr;
+
+pub const LIT: char = '#';
+pub const DARK: char = '.';
+
+/// A black-and-white picture, for puzzles whose answer has to be read off a screen.
+/// Displays as `#`/`.` rows separated by newlines, so it can be returned from a solution directly.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Bitmap {
+    width: usize,
+    height: usize,
+    pixels: Vec<bool>,
+}
+
+impl Bitmap {
+    /// A bitmap with all pixels dark.
+    pub fn new(width: usize, height: usize) -> Bitmap {
+        Bitmap {
+            width,
+            height,
+            pixels: vec![false; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, f: impl Fn(usize, usize) -> bool) -> Bitmap {
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Bitmap {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns `false` for pixels outside of the bitmap.
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height && self.pixels[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, lit: bool) {
+        assert!(
+            x < self.width && y < self.height,
+            "pixel ({}, {}) is outside of a {}x{} bitmap",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+        self.pixels[y * self.width + x] = lit;
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[bool]> {
+        self.pixels.chunks(self.width.max(1))
+    }
+}
+
+impl fmt::Display for Bitmap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for &lit in row {
+                write!(f, "{}", if lit { LIT } else { DARK })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses `#`/`.` rows. Leading and trailing blank lines are ignored.
+impl FromStr for Bitmap {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows: Vec<&str> = s.trim_matches('\n').lines().map(str::trim_end).collect();
+        let width = rows.first().map_or(0, |row| row.chars().count());
+        let mut pixels = Vec::with_capacity(width * rows.len());
+
+        for (y, row) in rows.iter().enumerate() {
+            if row.chars().count() != width {
+                return Err(format!(
+                    "row {} has {} pixels, expected {}",
+                    y + 1,
+                    row.chars().count(),
+                    width
+                ));
+            }
+            for c in row.chars() {
+                match c {
+                    LIT => pixels.push(true),
+                    DARK => pixels.push(false),
+                    c => return Err(format!("unexpected pixel {:?} in row {}", c, y + 1)),
+                }
+            }
+        }
+
+        Ok(Bitmap {
+            width,
+            height: rows.len(),
+            pixels,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let bitmap = Bitmap::from_fn(3, 2, |x, y| x == y);
+        assert_eq!(bitmap.to_string(), "#..\n.#.");
+    }
+
+    #[test]
+    fn test_from_str() {
+        let bitmap: Bitmap = "\n#..\n.#.\n".parse().unwrap();
+        assert_eq!(bitmap, Bitmap::from_fn(3, 2, |x, y| x == y));
+        assert!(bitmap.get(1, 1));
+        assert!(!bitmap.get(5, 1));
+
+        assert!("#.\n#".parse::<Bitmap>().is_err());
+        assert!("#x".parse::<Bitmap>().is_err());
+    }
+
+    #[test]
+    fn test_set() {
+        let mut bitmap = Bitmap::new(2, 2);
+        bitmap.set(1, 0, true);
+        assert_eq!(bitmap.to_string(), ".#\n..");
+    }
+}
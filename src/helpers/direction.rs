@@ -0,0 +1,290 @@
+//! The directions to walk in on a map, with y growing down like everywhere in this crate:
+//! [`Direction::Up`] is `Vector { x: 0, y: -1 }`.
+//!
+//! Puzzles write directions in several ways; all of `U`, `N` and `^` parse as `Up`.
+//!
+//! ```
+//! use advent_of_code::helpers::direction::Direction;
+//! use rusttype::Vector;
+//!
+//! let direction: Direction = "L".parse().unwrap();
+//! assert_eq!(direction.turn_right(), Direction::Up);
+//! assert_eq!(direction.vector(), Vector { x: -1, y: 0 });
+//! ```
+use rusttype::Vector;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+type V = Vector<i32>;
+
+/// One of the four directions along the axes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise from [`Up`](Direction::Up), in the same order as
+    /// [`DIRECTIONS`](super::DIRECTIONS).
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Direction> {
+        Direction::ALL.into_iter()
+    }
+
+    /// The direction after turning 90° clockwise, as seen on a map with y growing down.
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    /// The direction after turning 90° counterclockwise.
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn vector(self) -> V {
+        match self {
+            Direction::Up => V { x: 0, y: -1 },
+            Direction::Right => V { x: 1, y: 0 },
+            Direction::Down => V { x: 0, y: 1 },
+            Direction::Left => V { x: -1, y: 0 },
+        }
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+}
+
+impl From<Direction> for V {
+    fn from(direction: Direction) -> Self {
+        direction.vector()
+    }
+}
+
+impl TryFrom<V> for Direction {
+    type Error = String;
+
+    fn try_from(vector: V) -> Result<Self, Self::Error> {
+        Direction::iter()
+            .find(|d| d.vector() == vector)
+            .ok_or_else(|| format!("{:?} is not a unit vector along an axis", vector))
+    }
+}
+
+/// `U`/`D`/`L`/`R`, `N`/`S`/`W`/`E` or `^`/`v`/`<`/`>`.
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | 'N' | '^' => Ok(Direction::Up),
+            'R' | 'E' | '>' => Ok(Direction::Right),
+            'D' | 'S' | 'v' => Ok(Direction::Down),
+            'L' | 'W' | '<' => Ok(Direction::Left),
+            _ => Err(format!("unknown direction `{}`", c)),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => Err(format!("unknown direction `{}`", s)),
+        }
+    }
+}
+
+/// One of the four directions along the axes or the four diagonals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// All directions, clockwise from [`Up`](Direction8::Up).
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Direction8> {
+        Direction8::ALL.into_iter()
+    }
+
+    /// The direction after turning 45° clockwise.
+    pub fn turn_right(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// The direction after turning 45° counterclockwise.
+    pub fn turn_left(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn vector(self) -> V {
+        match self {
+            Direction8::Up => V { x: 0, y: -1 },
+            Direction8::UpRight => V { x: 1, y: -1 },
+            Direction8::Right => V { x: 1, y: 0 },
+            Direction8::DownRight => V { x: 1, y: 1 },
+            Direction8::Down => V { x: 0, y: 1 },
+            Direction8::DownLeft => V { x: -1, y: 1 },
+            Direction8::Left => V { x: -1, y: 0 },
+            Direction8::UpLeft => V { x: -1, y: -1 },
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+impl From<Direction8> for V {
+    fn from(direction: Direction8) -> Self {
+        direction.vector()
+    }
+}
+
+impl TryFrom<V> for Direction8 {
+    type Error = String;
+
+    fn try_from(vector: V) -> Result<Self, Self::Error> {
+        Direction8::iter()
+            .find(|d| d.vector() == vector)
+            .ok_or_else(|| format!("{:?} is not a unit vector", vector))
+    }
+}
+
+/// Anything a [`Direction`] parses from, or a diagonal written as `NE`, `SE`, `SW` or `NW`.
+impl FromStr for Direction8 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" => Ok(Direction8::UpRight),
+            "SE" => Ok(Direction8::DownRight),
+            "SW" => Ok(Direction8::DownLeft),
+            "NW" => Ok(Direction8::UpLeft),
+            _ => s.parse::<Direction>().map(Direction8::from),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        for d in Direction::iter() {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.turn_right().turn_right(), d.opposite());
+            assert_eq!(d.vector() + d.opposite().vector(), V { x: 0, y: 0 });
+            // turning right on a map with y growing down is a clockwise rotation.
+            let v = d.vector();
+            assert_eq!(d.turn_right().vector(), V { x: -v.y, y: v.x });
+        }
+
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownRight.opposite(), Direction8::UpLeft);
+        for d in Direction8::iter() {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.vector() + d.opposite().vector(), V { x: 0, y: 0 });
+        }
+    }
+
+    #[test]
+    fn test_vectors() {
+        assert_eq!(V::from(Direction::Up), V { x: 0, y: -1 });
+        assert_eq!(
+            Direction::iter().map(Direction::vector).collect::<Vec<_>>(),
+            crate::helpers::DIRECTIONS
+        );
+        assert_eq!(Direction::try_from(V { x: 0, y: 1 }), Ok(Direction::Down));
+        assert!(Direction::try_from(V { x: 1, y: 1 }).is_err());
+        assert_eq!(
+            Direction8::try_from(V { x: 1, y: 1 }),
+            Ok(Direction8::DownRight)
+        );
+        assert!(Direction8::try_from(V { x: 2, y: 0 }).is_err());
+
+        let mut neighbours = crate::helpers::NEIGHBOURS.to_vec();
+        neighbours.sort_by_key(|&v| Direction8::try_from(v).unwrap());
+        assert_eq!(
+            Direction8::iter()
+                .map(Direction8::vector)
+                .collect::<Vec<_>>(),
+            neighbours
+        );
+        for d in Direction::iter() {
+            assert_eq!(Direction8::from(d).vector(), d.vector());
+            assert!(!Direction8::from(d).is_diagonal());
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        for (text, expected) in [
+            ("URDL", Direction::ALL),
+            ("NESW", Direction::ALL),
+            ("^>v<", Direction::ALL),
+        ] {
+            let parsed: Vec<Direction> = text.chars().map(|c| c.try_into().unwrap()).collect();
+            assert_eq!(parsed, expected);
+        }
+        assert_eq!("R".parse(), Ok(Direction::Right));
+        assert_eq!(
+            "x".parse::<Direction>(),
+            Err("unknown direction `x`".to_string())
+        );
+        assert!("UR".parse::<Direction>().is_err());
+
+        assert_eq!("NW".parse(), Ok(Direction8::UpLeft));
+        assert_eq!("v".parse(), Ok(Direction8::Down));
+        assert!("NN".parse::<Direction8>().is_err());
+    }
+}
@@ -0,0 +1,447 @@
+//! Two-dimensional maps, the most common shape of puzzle input.
+//!
+//! [`Grid`] stores every cell of a rectangular map, e.g. the digits of 2022 day 8.
+//! [`SparseGrid`] only stores occupied cells and grows as they are inserted,
+//! for open-ended maps like the cave of 2022 day 14.
+//! Both use `rusttype` points with `y` growing downwards, like [`DIRECTIONS`](super::DIRECTIONS).
+use super::{DIRECTIONS, NEIGHBOURS};
+use rusttype::{Point, Vector};
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+type P = Point<i32>;
+type V = Vector<i32>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(P) -> T) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| point(x, y)))
+            .map(&mut f)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a map with one character per cell, converting each with `f`.
+    /// Leading and trailing blank lines are ignored, all rows must have the same length.
+    /// Spaces are cells like any other character, only line endings are removed.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Result<Grid<T>, String> {
+        let rows: Vec<&str> = input
+            .trim_matches(['\n', '\r'])
+            .lines()
+            .map(|row| row.strip_suffix('\r').unwrap_or(row))
+            .collect();
+        let width = rows.first().map_or(0, |row| row.chars().count());
+        let mut cells = Vec::with_capacity(width * rows.len());
+
+        for (y, row) in rows.iter().enumerate() {
+            if row.chars().count() != width {
+                return Err(format!(
+                    "row {} has {} cells, expected {}",
+                    y + 1,
+                    row.chars().count(),
+                    width
+                ));
+            }
+            cells.extend(row.chars().map(&mut f));
+        }
+
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: P) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+
+    fn index_of(&self, p: P) -> Option<usize> {
+        self.contains(p)
+            .then(|| p.y as usize * self.width + p.x as usize)
+    }
+
+    /// Returns `None` for points outside of the grid.
+    pub fn get(&self, p: P) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: P) -> Option<&mut T> {
+        self.index_of(p).map(|i| &mut self.cells[i])
+    }
+
+    /// All points of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = P> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| point(x, y)))
+    }
+
+    /// All cells with their points, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (P, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// The first point whose cell satisfies `predicate`, row by row.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<P> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(p, _)| p)
+    }
+
+    /// The up to four points next to `p` in [`DIRECTIONS`] that are inside the grid.
+    pub fn neighbours(&self, p: P) -> impl Iterator<Item = P> + '_ {
+        DIRECTIONS
+            .iter()
+            .map(move |&d| p + d)
+            .filter(|&n| self.contains(n))
+    }
+
+    /// The up to eight points around `p`, including diagonals, that are inside the grid.
+    pub fn neighbours8(&self, p: P) -> impl Iterator<Item = P> + '_ {
+        NEIGHBOURS
+            .iter()
+            .map(move |&d| p + d)
+            .filter(|&n| self.contains(n))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside of the grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The cells seen when walking from `p` in `direction` until leaving the grid,
+    /// not including `p` itself. E.g. the trees in the line of sight of a tree.
+    pub fn ray(&self, p: P, direction: V) -> impl Iterator<Item = (P, &T)> {
+        std::iter::successors(Some(p + direction), move |&p| Some(p + direction))
+            .map_while(|p| self.get(p).map(|cell| (p, cell)))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, turning rows into columns.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |p| self[point(p.y, p.x)].clone())
+    }
+
+    /// Rotates the grid by 90°, so that the left column becomes the top row.
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[point(p.y as usize, self.height - 1 - p.x as usize)].clone()
+        })
+    }
+
+    /// Rotates the grid by 90°, so that the top row becomes the left column.
+    pub fn rotate_counterclockwise(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[point(self.width - 1 - p.y as usize, p.x as usize)].clone()
+        })
+    }
+}
+
+/// Panics for points outside of the grid.
+impl<T> Index<P> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: P) -> &T {
+        match self.index_of(p) {
+            Some(i) => &self.cells[i],
+            None => panic!(
+                "({}, {}) is outside of a {}x{} grid",
+                p.x, p.y, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, p: P) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(p).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) is outside of a {}x{} grid",
+                p.x, p.y, width, height
+            )
+        })
+    }
+}
+
+/// Displays every row on its own line, without separators between cells.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, |c| c)
+    }
+}
+
+/// A map that only stores occupied cells. Its bounds grow to include every inserted point.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<P, T>,
+    bounds: Option<(P, P)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid::default()
+    }
+
+    /// Returns the previous cell at `p`, if there was one.
+    pub fn insert(&mut self, p: P, cell: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            None => (p, p),
+            Some((min, max)) => (
+                point(min.x.min(p.x), min.y.min(p.y)),
+                point(max.x.max(p.x), max.y.max(p.y)),
+            ),
+        });
+        self.cells.insert(p, cell)
+    }
+
+    pub fn get(&self, p: P) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    pub fn get_mut(&mut self, p: P) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+
+    pub fn contains(&self, p: P) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The smallest and largest corner of the rectangle containing every point ever inserted,
+    /// or `None` if nothing was inserted yet.
+    pub fn bounds(&self) -> Option<(P, P)> {
+        self.bounds
+    }
+
+    /// The occupied cells in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (P, &T)> {
+        self.cells.iter().map(|(&p, cell)| (p, cell))
+    }
+
+    /// Converts the area within the bounds to a dense grid, filling empty cells with `empty`.
+    pub fn to_grid(&self, empty: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let Some((min, max)) = self.bounds else {
+            return Grid::new(0, 0, empty);
+        };
+        Grid::from_fn(
+            (max.x - min.x + 1) as usize,
+            (max.y - min.y + 1) as usize,
+            |p| {
+                let p = p + Vector { x: min.x, y: min.y };
+                self.get(p).cloned().unwrap_or_else(|| empty.clone())
+            },
+        )
+    }
+}
+
+/// Displays the area within the bounds, showing empty cells as `.`.
+impl<T: fmt::Display> fmt::Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((min, max)) = self.bounds else {
+            return Ok(());
+        };
+        for y in min.y..=max.y {
+            if y > min.y {
+                writeln!(f)?;
+            }
+            for x in min.x..=max.x {
+                match self.get(Point { x, y }) {
+                    Some(cell) => write!(f, "{}", cell)?,
+                    None => write!(f, ".")?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn point<N: TryInto<i32>>(x: N, y: N) -> P
+where
+    N::Error: fmt::Debug,
+{
+    Point {
+        x: x.try_into().unwrap(),
+        y: y.try_into().unwrap(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRID: &str = "123\n456\n";
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, |c| c.to_digit(10).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits(GRID);
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point { x: 2, y: 1 }), Some(&6));
+        assert_eq!(grid.get(Point { x: 3, y: 0 }), None);
+        assert_eq!(grid.get(Point { x: 0, y: -1 }), None);
+        assert_eq!(grid[Point { x: 1, y: 0 }], 2);
+        assert_eq!(grid.to_string(), "123\n456");
+        assert_eq!(grid.position(|&d| d == 5), Some(Point { x: 1, y: 1 }));
+
+        assert!("ab\nc".parse::<Grid<char>>().is_err());
+    }
+
+    #[test]
+    fn test_parse_trailing_spaces() {
+        // spaces are cells, e.g. the parts of a ragged map that are not on it.
+        let grid: Grid<char> = "\n  .#  \n ..#  \r\n#...  \n".parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (6, 3));
+        assert_eq!(grid.row(0), [' ', ' ', '.', '#', ' ', ' ']);
+        assert_eq!(grid[Point { x: 5, y: 2 }], ' ');
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits(GRID);
+        let corner: Vec<P> = grid.neighbours(Point { x: 0, y: 0 }).collect();
+        assert_eq!(corner, [Point { x: 1, y: 0 }, Point { x: 0, y: 1 }]);
+        assert_eq!(grid.neighbours8(Point { x: 1, y: 0 }).count(), 5);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = digits(GRID);
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [3, 6]);
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = digits("12345\n67890");
+        let ray: Vec<u32> = grid
+            .ray(Point { x: 1, y: 0 }, Vector { x: 1, y: 0 })
+            .map(|(_, &d)| d)
+            .collect();
+        assert_eq!(ray, [3, 4, 5]);
+        assert_eq!(
+            grid.ray(Point { x: 1, y: 0 }, Vector { x: 0, y: -1 })
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = digits(GRID);
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+        assert_eq!(grid.rotate_clockwise().to_string(), "41\n52\n63");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "36\n25\n14");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+        assert_eq!(grid.map(|d| d * 2).row(0), [2, 4, 6]);
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.to_string(), "");
+
+        grid.insert(Point { x: 500, y: 0 }, '+');
+        grid.insert(Point { x: 498, y: 2 }, '#');
+        assert_eq!(grid.insert(Point { x: 498, y: 2 }, 'o'), Some('#'));
+        assert_eq!(
+            grid.bounds(),
+            Some((Point { x: 498, y: 0 }, Point { x: 500, y: 2 }))
+        );
+        assert_eq!(grid.len(), 2);
+        assert!(grid.contains(Point { x: 500, y: 0 }));
+        assert_eq!(grid.to_string(), "..+\n...\no..");
+        assert_eq!(grid.to_grid(' ').to_string(), "  +\n   \no  ");
+    }
+}
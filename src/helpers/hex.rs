@@ -0,0 +1,154 @@
+//! Hexagonal grids in axial coordinates `(q, r)`, like the floor tiles of 2020 day 24.
+//!
+//! The third cube coordinate is `s = -q - r`, so moving to a neighbour changes two of the
+//! three coordinates by one. The distance between two hexes is the largest change of a
+//! cube coordinate.
+//!
+//! ```
+//! use advent_of_code::helpers::hex::Hex;
+//!
+//! let hex = Hex::new(2, -1);
+//! assert_eq!(hex.s(), -1);
+//! assert_eq!(hex.distance(&Hex::origin()), 2);
+//! assert!(hex.neighbours().all(|n| n.distance(&hex) == 1));
+//! ```
+use std::ops::{Add, AddAssign, Mul, Sub};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Hex {
+    pub q: i32,
+    pub r: i32,
+}
+
+impl Hex {
+    /// The offsets of the six neighbours, counterclockwise starting from `+q`.
+    /// For hexes with a pointy top these are E, NE, NW, W, SW and SE;
+    /// with a flat top they are SE, NE, N, NW, SW and S.
+    pub const NEIGHBOURS: [Hex; 6] = [
+        Hex { q: 1, r: 0 },
+        Hex { q: 1, r: -1 },
+        Hex { q: 0, r: -1 },
+        Hex { q: -1, r: 0 },
+        Hex { q: -1, r: 1 },
+        Hex { q: 0, r: 1 },
+    ];
+
+    pub fn new(q: i32, r: i32) -> Hex {
+        Hex { q, r }
+    }
+
+    pub fn origin() -> Hex {
+        Hex::new(0, 0)
+    }
+
+    /// The hex with cube coordinates `(q, r, s)`, `None` unless `q + r + s == 0`.
+    pub fn from_cube(q: i32, r: i32, s: i32) -> Option<Hex> {
+        (q + r + s == 0).then(|| Hex::new(q, r))
+    }
+
+    pub fn s(&self) -> i32 {
+        -self.q - self.r
+    }
+
+    /// The cube coordinates `(q, r, s)`.
+    pub fn cube(&self) -> (i32, i32, i32) {
+        (self.q, self.r, self.s())
+    }
+
+    /// The number of steps between the hexes.
+    pub fn distance(&self, other: &Hex) -> i32 {
+        let d = *self - *other;
+        d.q.abs().max(d.r.abs()).max(d.s().abs())
+    }
+
+    pub fn neighbours(self) -> impl Iterator<Item = Hex> {
+        Hex::NEIGHBOURS.into_iter().map(move |offset| self + offset)
+    }
+
+    /// The hexes at exactly `radius` steps, starting in the direction of `NEIGHBOURS[4]`.
+    pub fn ring(self, radius: i32) -> impl Iterator<Item = Hex> {
+        let start = self + Hex::NEIGHBOURS[4] * radius;
+        let steps = if radius == 0 { 1 } else { 6 * radius };
+        (0..steps).scan(start, move |hex, i| {
+            let current = *hex;
+            *hex += Hex::NEIGHBOURS[(i / radius.max(1)) as usize % 6];
+            Some(current)
+        })
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, other: Hex) -> Hex {
+        Hex::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, other: Hex) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+
+    fn sub(self, other: Hex) -> Hex {
+        Hex::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl Mul<i32> for Hex {
+    type Output = Hex;
+
+    fn mul(self, factor: i32) -> Hex {
+        Hex::new(self.q * factor, self.r * factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_cube() {
+        assert_eq!(Hex::from_cube(1, -3, 2), Some(Hex::new(1, -3)));
+        assert_eq!(Hex::from_cube(1, 1, 1), None);
+        assert_eq!(Hex::new(1, -3).cube(), (1, -3, 2));
+        for offset in Hex::NEIGHBOURS {
+            let (q, r, s) = offset.cube();
+            assert_eq!(q + r + s, 0);
+            assert_eq!(offset.distance(&Hex::origin()), 1);
+        }
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let hex = Hex::new(3, -7);
+        let neighbours: HashSet<Hex> = hex.neighbours().collect();
+        assert_eq!(neighbours.len(), 6);
+        // opposite neighbours are three steps apart in the list.
+        for i in 0..3 {
+            assert_eq!(Hex::NEIGHBOURS[i] + Hex::NEIGHBOURS[i + 3], Hex::origin());
+        }
+        // stepping to every neighbour in turn leads back to the start.
+        let end = Hex::NEIGHBOURS.iter().fold(hex, |h, &offset| h + offset);
+        assert_eq!(end, hex);
+    }
+
+    #[test]
+    fn test_distance_and_rings() {
+        let a = Hex::new(-2, 3);
+        assert_eq!(a.distance(&Hex::new(1, -1)), 4);
+        assert_eq!(a.distance(&a), 0);
+
+        assert_eq!(a.ring(0).collect::<Vec<_>>(), [a]);
+        for radius in 1..4 {
+            let ring: HashSet<Hex> = a.ring(radius).collect();
+            assert_eq!(ring.len(), 6 * radius as usize);
+            assert!(ring.iter().all(|h| h.distance(&a) == radius));
+        }
+    }
+}
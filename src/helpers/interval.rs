@@ -0,0 +1,470 @@
+//! Sets of integers stored as sorted, disjoint intervals, e.g. the columns of a row that
+//! some sensors can see in 2022 day 15.
+//!
+//! Intervals are half-open internally, `[start, end)`, so that lengths and gaps don't need
+//! `+ 1` corrections. [`Interval::inclusive`] builds one from the inclusive bounds that most
+//! puzzles use. Overlapping and adjacent intervals are merged: `1..=3` and `4..=6` become `1..=6`.
+//!
+//! ```
+//! use advent_of_code::helpers::interval::{Interval, IntervalSet};
+//!
+//! let set: IntervalSet<i32> = [Interval::inclusive(1, 3), Interval::inclusive(4, 6)]
+//!     .into_iter()
+//!     .collect();
+//! assert_eq!(set.intervals(), [Interval::inclusive(1, 6)]);
+//! assert_eq!(set.len(), 6);
+//! ```
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::{Add, Sub};
+
+/// The integer types that intervals can be made of.
+pub trait Integer:
+    Copy + Ord + fmt::Debug + Add<Output = Self> + Sub<Output = Self> + From<u8>
+{
+    /// `self + 1`, or `self` if it is the largest value of the type.
+    fn saturating_next(self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            fn saturating_next(self) -> Self {
+                self.saturating_add(1)
+            }
+        })*
+    };
+}
+
+impl_integer!(i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// The integers from `start` up to, but not including, `end`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Integer> Interval<T> {
+    /// The integers `start..end`. Empty if `end <= start`.
+    pub fn exclusive(start: T, end: T) -> Interval<T> {
+        Interval {
+            start,
+            end: end.max(start),
+        }
+    }
+
+    /// The integers `start..=last`. Empty if `last < start`.
+    /// The end is stored exclusively, so the largest value of `T` can't be included:
+    /// a `last` of `T::MAX` ends the interval just before it.
+    pub fn inclusive(start: T, last: T) -> Interval<T> {
+        if last < start {
+            Interval::exclusive(start, start)
+        } else {
+            Interval::exclusive(start, last.saturating_next())
+        }
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    /// The first integer after the interval.
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    /// The last integer in the interval, `None` if it is empty.
+    pub fn last(&self) -> Option<T> {
+        (!self.is_empty()).then(|| self.end - T::from(1))
+    }
+
+    pub fn len(&self) -> T {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Whether every integer of `other` is in this interval.
+    pub fn contains_interval(&self, other: &Interval<T>) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    /// Whether the intervals have an integer in common.
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    pub fn intersection(&self, other: &Interval<T>) -> Interval<T> {
+        Interval::exclusive(self.start.max(other.start), self.end.min(other.end))
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}..{:?}", self.start, self.end)
+    }
+}
+
+/// A set of integers, stored as the sorted intervals it covers.
+/// Intervals never overlap or touch, so there is exactly one way to store every set.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// The intervals of the set, sorted by their start.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of integers in the set.
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::from(0), |len, interval| len + interval.len())
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(Interval::start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().and_then(Interval::last)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals.get(i).is_some_and(|i| i.contains(value))
+    }
+
+    /// Whether every integer of `interval` is in the set.
+    pub fn contains_interval(&self, interval: &Interval<T>) -> bool {
+        if interval.is_empty() {
+            return true;
+        }
+        let i = self.intervals.partition_point(|i| i.end <= interval.start);
+        self.intervals
+            .get(i)
+            .is_some_and(|i| i.contains_interval(interval))
+    }
+
+    /// Adds the integers of `interval`, merging it with the intervals it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+
+        let merged = if first < last {
+            Interval::exclusive(
+                interval.start.min(self.intervals[first].start),
+                interval.end.max(self.intervals[last - 1].end),
+            )
+        } else {
+            interval
+        };
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Removes the integers of `interval`, splitting the interval it falls into if needed.
+    pub fn remove(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let first = self.intervals.partition_point(|i| i.end <= interval.start);
+        let last = self.intervals.partition_point(|i| i.start < interval.end);
+        if first >= last {
+            return;
+        }
+
+        let before = Interval::exclusive(self.intervals[first].start, interval.start);
+        let after = Interval::exclusive(interval.end, self.intervals[last - 1].end);
+        let kept = [before, after].into_iter().filter(|i| !i.is_empty());
+        self.intervals.splice(first..last, kept);
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union = self.clone();
+        union.extend(other.intervals.iter().copied());
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = Vec::new();
+        let (mut a, mut b) = (
+            self.intervals.iter().peekable(),
+            other.intervals.iter().peekable(),
+        );
+
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let common = x.intersection(y);
+            if !common.is_empty() {
+                intervals.push(common);
+            }
+            if x.end < y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        // the pieces are disjoint, and can't touch since they come from disjoint intervals.
+        IntervalSet { intervals }
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut difference = self.clone();
+        for &interval in &other.intervals {
+            difference.remove(interval);
+        }
+        difference
+    }
+
+    /// The integers of `bounds` that are not in the set.
+    pub fn complement(&self, bounds: Interval<T>) -> IntervalSet<T> {
+        IntervalSet::from(bounds).difference(self)
+    }
+
+    /// The intervals between the intervals of the set, i.e. its complement within
+    /// [`min`](Self::min) and [`max`](Self::max).
+    pub fn gaps(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+        self.intervals
+            .windows(2)
+            .map(|pair| Interval::exclusive(pair[0].end, pair[1].start))
+    }
+
+    pub fn is_subset(&self, other: &IntervalSet<T>) -> bool {
+        self.intervals.iter().all(|i| other.contains_interval(i))
+    }
+
+    /// Whether the sets have an integer in common.
+    pub fn overlaps(&self, other: &IntervalSet<T>) -> bool {
+        !self.intersection(other).is_empty()
+    }
+}
+
+impl<T: Integer> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Integer> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(interval);
+        set
+    }
+}
+
+impl<T: Integer> Extend<Interval<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Interval<T>>>(&mut self, intervals: I) {
+        for interval in intervals {
+            self.insert(interval);
+        }
+    }
+}
+
+impl<T: Integer> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(intervals: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(intervals);
+        set
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for IntervalSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(&self.intervals).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i32, i32)]) -> IntervalSet<i32> {
+        intervals
+            .iter()
+            .map(|&(start, last)| Interval::inclusive(start, last))
+            .collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let interval = Interval::inclusive(3, 5);
+        assert_eq!(interval, Interval::exclusive(3, 6));
+        assert_eq!(
+            (interval.start(), interval.end(), interval.last()),
+            (3, 6, Some(5))
+        );
+        assert_eq!(interval.len(), 3);
+        assert!(interval.contains(3) && interval.contains(5));
+        assert!(!interval.contains(2) && !interval.contains(6));
+
+        let empty = Interval::inclusive(5, 3);
+        assert!(empty.is_empty());
+        assert_eq!((empty.len(), empty.last()), (0, None));
+        assert!(Interval::exclusive(4, 4).is_empty());
+
+        assert!(interval.overlaps(&Interval::inclusive(5, 9)));
+        assert!(!interval.overlaps(&Interval::inclusive(6, 9)));
+        assert!(interval.contains_interval(&Interval::inclusive(4, 5)));
+        assert!(!interval.contains_interval(&Interval::inclusive(4, 6)));
+        assert_eq!(
+            interval.intersection(&Interval::inclusive(4, 10)),
+            Interval::inclusive(4, 5)
+        );
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        // a later interval that contains the previous one.
+        assert_eq!(set(&[(2, 4), (0, 10)]), set(&[(0, 10)]));
+        // adjacent intervals.
+        assert_eq!(
+            set(&[(1, 3), (4, 6)]).intervals(),
+            [Interval::inclusive(1, 6)]
+        );
+        // an interval that bridges several others.
+        let mut bridged = set(&[(0, 1), (4, 5), (8, 9), (20, 21)]);
+        assert_eq!(bridged.intervals().len(), 4);
+        bridged.insert(Interval::inclusive(2, 10));
+        assert_eq!(bridged, set(&[(0, 10), (20, 21)]));
+        // disjoint intervals stay sorted, whatever the order they are inserted in.
+        assert_eq!(
+            set(&[(10, 12), (-5, -3), (3, 4)]).intervals(),
+            [
+                Interval::inclusive(-5, -3),
+                Interval::inclusive(3, 4),
+                Interval::inclusive(10, 12)
+            ]
+        );
+        // empty intervals are ignored.
+        assert!(set(&[(3, 2)]).is_empty());
+    }
+
+    #[test]
+    fn test_membership() {
+        let s = set(&[(-2, 2), (5, 5), (8, 10)]);
+        assert_eq!(s.len(), 9);
+        assert_eq!((s.min(), s.max()), (Some(-2), Some(10)));
+        for value in -5..15 {
+            let expected = (-2..=2).contains(&value) || value == 5 || (8..=10).contains(&value);
+            assert_eq!(s.contains(value), expected, "{}", value);
+        }
+        assert!(s.contains_interval(&Interval::inclusive(8, 10)));
+        assert!(!s.contains_interval(&Interval::inclusive(2, 5)));
+
+        let empty = IntervalSet::<i32>::new();
+        assert_eq!((empty.len(), empty.min(), empty.max()), (0, None, None));
+        assert!(!empty.contains(0));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut s = set(&[(0, 10), (20, 30)]);
+        s.remove(Interval::inclusive(3, 5));
+        assert_eq!(s, set(&[(0, 2), (6, 10), (20, 30)]));
+        s.remove(Interval::inclusive(8, 25));
+        assert_eq!(s, set(&[(0, 2), (6, 7), (26, 30)]));
+        s.remove(Interval::inclusive(-10, 0));
+        s.remove(Interval::inclusive(11, 19));
+        assert_eq!(s, set(&[(1, 2), (6, 7), (26, 30)]));
+        s.remove(Interval::inclusive(-100, 100));
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(0, 5), (10, 15)]);
+        let b = set(&[(3, 12), (20, 22)]);
+
+        assert_eq!(a.union(&b), set(&[(0, 15), (20, 22)]));
+        assert_eq!(a.intersection(&b), set(&[(3, 5), (10, 12)]));
+        assert_eq!(b.intersection(&a), a.intersection(&b));
+        assert_eq!(a.difference(&b), set(&[(0, 2), (13, 15)]));
+        assert_eq!(b.difference(&a), set(&[(6, 9), (20, 22)]));
+        assert_eq!(
+            a.complement(Interval::inclusive(-2, 20)),
+            set(&[(-2, -1), (6, 9), (16, 20)])
+        );
+        assert!(a.complement(Interval::inclusive(1, 4)).is_empty());
+
+        assert!(set(&[(1, 2), (11, 11)]).is_subset(&a));
+        assert!(!b.is_subset(&a));
+        assert!(a.overlaps(&b));
+        assert!(!a.overlaps(&set(&[(6, 9)])));
+    }
+
+    #[test]
+    fn test_set_operations_match_hash_sets() {
+        use std::collections::HashSet;
+
+        let to_hash_set = |s: &IntervalSet<i32>| -> HashSet<i32> {
+            s.intervals()
+                .iter()
+                .flat_map(|i| i.start()..i.end())
+                .collect()
+        };
+        let a = set(&[(-3, 0), (2, 2), (4, 9), (11, 12), (15, 20)]);
+        let b = set(&[(-1, 3), (6, 6), (8, 16), (19, 25)]);
+        let (ha, hb) = (to_hash_set(&a), to_hash_set(&b));
+
+        assert_eq!(to_hash_set(&a.union(&b)), &ha | &hb);
+        assert_eq!(to_hash_set(&a.intersection(&b)), &ha & &hb);
+        assert_eq!(to_hash_set(&a.difference(&b)), &ha - &hb);
+        assert_eq!(a.len() as usize, ha.len());
+    }
+
+    #[test]
+    fn test_gaps() {
+        let s = set(&[(0, 2), (5, 5), (7, 9)]);
+        assert_eq!(
+            s.gaps().collect::<Vec<_>>(),
+            [Interval::inclusive(3, 4), Interval::inclusive(6, 6)]
+        );
+        assert_eq!(set(&[(0, 9)]).gaps().count(), 0);
+        assert_eq!(
+            format!("{:?}", s),
+            "{0..3, 5..6, 7..10}",
+            "Debug shows half-open intervals"
+        );
+    }
+
+    #[test]
+    fn test_unsigned() {
+        let s: IntervalSet<u64> = [Interval::inclusive(0, 4), Interval::inclusive(2, 7)]
+            .into_iter()
+            .collect();
+        assert_eq!(s.len(), 8);
+        assert_eq!(s.complement(Interval::exclusive(0, 10)).len(), 2);
+    }
+
+    #[test]
+    fn test_inclusive_max() {
+        let interval = Interval::inclusive(0u8, u8::MAX);
+        assert_eq!(interval, Interval::exclusive(0, u8::MAX));
+        assert_eq!(interval.last(), Some(254));
+        assert!(!interval.contains(u8::MAX));
+        assert!(Interval::inclusive(i32::MAX, i32::MAX).is_empty());
+    }
+}
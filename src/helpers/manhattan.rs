@@ -0,0 +1,294 @@
+//! Geometry with the Manhattan (L1) distance, where the points within a distance of a center
+//! form a diamond, like the area a sensor covers in 2022 day 15.
+//!
+//! Rotating the plane by 45° turns diamonds into squares: [`rotate`] maps `(x, y)` to
+//! `(u, v) = (x + y, x - y)`. The edges of a diamond lie on the lines `u = c` and `v = c`,
+//! which makes intersections of edges easy to find. Only points where `u` and `v` are both
+//! even or both odd map back to integer points, see [`unrotate`].
+//!
+//! ```
+//! use advent_of_code::helpers::manhattan::Diamond;
+//! use rusttype::Point;
+//!
+//! let diamond = Diamond::new(Point { x: 0, y: 0 }, 2);
+//! assert!(diamond.contains(Point { x: 1, y: -1 }));
+//! assert!(!diamond.contains(Point { x: 2, y: 1 }));
+//! assert_eq!(diamond.perimeter().count(), 8);
+//! ```
+use super::interval::Interval;
+use rusttype::Point;
+
+type P = Point<i32>;
+
+pub fn distance(a: P, b: P) -> i32 {
+    (a.x - b.x).abs() + (a.y - b.y).abs()
+}
+
+/// The point in coordinates rotated by 45°, `(x + y, x - y)`.
+pub fn rotate(p: P) -> P {
+    P {
+        x: p.x + p.y,
+        y: p.x - p.y,
+    }
+}
+
+/// The inverse of [`rotate`]. `None` if the point lies between integer points.
+pub fn unrotate(p: P) -> Option<P> {
+    ((p.x + p.y) % 2 == 0).then(|| P {
+        x: (p.x + p.y) / 2,
+        y: (p.x - p.y) / 2,
+    })
+}
+
+/// The points within `radius` of `center`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Diamond {
+    pub center: P,
+    pub radius: i32,
+}
+
+impl Diamond {
+    pub fn new(center: P, radius: i32) -> Diamond {
+        Diamond { center, radius }
+    }
+
+    /// The diamond around `center` that just reaches `point`.
+    pub fn through(center: P, point: P) -> Diamond {
+        Diamond::new(center, distance(center, point))
+    }
+
+    /// The diamond with a radius that is larger by `by`.
+    pub fn grow(&self, by: i32) -> Diamond {
+        Diamond::new(self.center, self.radius + by)
+    }
+
+    pub fn contains(&self, p: P) -> bool {
+        distance(self.center, p) <= self.radius
+    }
+
+    pub fn on_perimeter(&self, p: P) -> bool {
+        distance(self.center, p) == self.radius
+    }
+
+    /// The columns of row `y` within the diamond.
+    pub fn row(&self, y: i32) -> Interval<i32> {
+        let reach = self.radius - (self.center.y - y).abs();
+        Interval::inclusive(self.center.x - reach, self.center.x + reach)
+    }
+
+    /// The top, right, bottom and left corner, with y growing down.
+    pub fn corners(&self) -> [P; 4] {
+        let Diamond {
+            center: c,
+            radius: r,
+        } = *self;
+        [
+            P { x: c.x, y: c.y - r },
+            P { x: c.x + r, y: c.y },
+            P { x: c.x, y: c.y + r },
+            P { x: c.x - r, y: c.y },
+        ]
+    }
+
+    /// The values of `u` on the two edges that lie on a line `u = c`, see [`rotate`].
+    pub fn u_edges(&self) -> [i32; 2] {
+        let u = rotate(self.center).x;
+        [u - self.radius, u + self.radius]
+    }
+
+    /// The values of `v` on the two edges that lie on a line `v = c`, see [`rotate`].
+    pub fn v_edges(&self) -> [i32; 2] {
+        let v = rotate(self.center).y;
+        [v - self.radius, v + self.radius]
+    }
+
+    /// The points on the perimeter of both diamonds where an edge of one crosses an edge of
+    /// the other. Edges that lie on the same line don't cross and are not included.
+    pub fn edge_intersections(&self, other: &Diamond) -> Vec<P> {
+        let crossings = |a: &Diamond, b: &Diamond| {
+            let v_edges = b.v_edges();
+            a.u_edges()
+                .into_iter()
+                .flat_map(move |u| v_edges.map(|v| P { x: u, y: v }))
+        };
+
+        let mut points: Vec<P> = crossings(self, other)
+            .chain(crossings(other, self))
+            .filter_map(unrotate)
+            .filter(|&p| self.on_perimeter(p) && other.on_perimeter(p))
+            .collect();
+        points.sort_by_key(|p| (p.y, p.x));
+        points.dedup();
+        points
+    }
+
+    /// The points of the perimeter in row `y`, none if the diamond doesn't reach it.
+    pub fn perimeter_in_row(&self, y: i32) -> Vec<P> {
+        let reach = self.radius - (self.center.y - y).abs();
+        match reach {
+            reach if reach < 0 => vec![],
+            0 => vec![P {
+                x: self.center.x,
+                y,
+            }],
+            reach => vec![
+                P {
+                    x: self.center.x - reach,
+                    y,
+                },
+                P {
+                    x: self.center.x + reach,
+                    y,
+                },
+            ],
+        }
+    }
+
+    /// The points of the perimeter in column `x`, none if the diamond doesn't reach it.
+    pub fn perimeter_in_column(&self, x: i32) -> Vec<P> {
+        let transposed = Diamond::new(
+            P {
+                x: self.center.y,
+                y: self.center.x,
+            },
+            self.radius,
+        );
+        transposed
+            .perimeter_in_row(x)
+            .into_iter()
+            .map(|p| P { x: p.y, y: p.x })
+            .collect()
+    }
+
+    /// The points at exactly `radius` from the center, clockwise from the top corner.
+    pub fn perimeter(&self) -> impl Iterator<Item = P> {
+        let Diamond {
+            center: c,
+            radius: r,
+        } = *self;
+        let steps = if r == 0 { 1 } else { 4 * r };
+        (0..steps).map(move |i| {
+            let (side, offset) = (i / r.max(1), i % r.max(1));
+            match side {
+                0 => P {
+                    x: c.x + offset,
+                    y: c.y - r + offset,
+                },
+                1 => P {
+                    x: c.x + r - offset,
+                    y: c.y + offset,
+                },
+                2 => P {
+                    x: c.x - offset,
+                    y: c.y + r - offset,
+                },
+                _ => P {
+                    x: c.x - r + offset,
+                    y: c.y - offset,
+                },
+            }
+        })
+    }
+
+    /// The points just outside the diamond, at `radius + 1` from the center.
+    pub fn outside(&self) -> impl Iterator<Item = P> {
+        self.grow(1).perimeter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn p(x: i32, y: i32) -> P {
+        P { x, y }
+    }
+
+    #[test]
+    fn test_rotate() {
+        assert_eq!(rotate(p(3, 1)), p(4, 2));
+        assert_eq!(unrotate(p(4, 2)), Some(p(3, 1)));
+        assert_eq!(unrotate(p(-3, 1)), Some(p(-1, -2)));
+        assert_eq!(unrotate(p(4, 1)), None);
+        for x in -3..3 {
+            for y in -3..3 {
+                assert_eq!(unrotate(rotate(p(x, y))), Some(p(x, y)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_diamond() {
+        let diamond = Diamond::through(p(8, 7), p(2, 10));
+        assert_eq!(diamond.radius, 9);
+        assert!(diamond.contains(p(8, -2)) && !diamond.contains(p(8, -3)));
+        assert!(diamond.on_perimeter(p(2, 10)));
+        assert_eq!(diamond.row(10), Interval::inclusive(2, 14));
+        assert!(diamond.row(17).is_empty());
+        assert_eq!(diamond.corners(), [p(8, -2), p(17, 7), p(8, 16), p(-1, 7)]);
+
+        // the edges in rotated coordinates are the sides of a square.
+        let rotated: Vec<P> = diamond.corners().into_iter().map(rotate).collect();
+        assert_eq!(diamond.u_edges(), [6, 24]);
+        assert_eq!(diamond.v_edges(), [-8, 10]);
+        assert!(rotated
+            .iter()
+            .all(|c| diamond.u_edges().contains(&c.x) || diamond.v_edges().contains(&c.y)));
+    }
+
+    #[test]
+    fn test_perimeter() {
+        for radius in 0..5 {
+            let diamond = Diamond::new(p(2, -1), radius);
+            let perimeter: Vec<P> = diamond.perimeter().collect();
+            let unique: HashSet<P> = perimeter.iter().copied().collect();
+
+            assert_eq!(perimeter.len(), (4 * radius).max(1) as usize);
+            assert_eq!(unique.len(), perimeter.len());
+            assert!(perimeter.iter().all(|&q| diamond.on_perimeter(q)));
+            // consecutive points are diagonal neighbours.
+            assert!(perimeter
+                .iter()
+                .zip(perimeter.iter().cycle().skip(1))
+                .all(|(a, b)| radius < 1 || (a.x - b.x).abs() == 1 && (a.y - b.y).abs() == 1));
+        }
+        assert_eq!(
+            Diamond::new(p(0, 0), 0).outside().collect::<Vec<_>>(),
+            [p(0, -1), p(1, 0), p(0, 1), p(-1, 0)]
+        );
+    }
+
+    #[test]
+    fn test_perimeter_in_row_and_column() {
+        let diamond = Diamond::new(p(2, -1), 3);
+        assert_eq!(diamond.perimeter_in_row(0), [p(0, 0), p(4, 0)]);
+        assert_eq!(diamond.perimeter_in_row(2), [p(2, 2)]);
+        assert!(diamond.perimeter_in_row(3).is_empty());
+        assert_eq!(diamond.perimeter_in_column(0), [p(0, -2), p(0, 0)]);
+        assert_eq!(diamond.perimeter_in_column(5), [p(5, -1)]);
+        assert!(diamond.perimeter_in_column(-2).is_empty());
+    }
+
+    #[test]
+    fn test_edge_intersections() {
+        let a = Diamond::new(p(0, 0), 2);
+        let b = Diamond::new(p(2, 0), 2);
+        assert_eq!(a.edge_intersections(&b), [p(1, -1), p(1, 1)]);
+        assert_eq!(b.edge_intersections(&a), [p(1, -1), p(1, 1)]);
+
+        // without edges on the same line, the crossings are all points on both perimeters.
+        let c = Diamond::new(p(4, 0), 4);
+        let on_both: Vec<P> = (-5..10)
+            .flat_map(|y| (-5..10).map(move |x| p(x, y)))
+            .filter(|&q| a.on_perimeter(q) && c.on_perimeter(q))
+            .collect();
+        assert!(!on_both.is_empty());
+        assert_eq!(a.edge_intersections(&c), on_both);
+
+        // crossings between integer points are skipped.
+        assert!(a.edge_intersections(&Diamond::new(p(1, 0), 2)).is_empty());
+        // diamonds too far apart don't cross.
+        assert!(a.edge_intersections(&Diamond::new(p(10, 0), 2)).is_empty());
+    }
+}
@@ -2,6 +2,17 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+pub mod direction;
+pub mod grid;
+pub mod hex;
+pub mod interval;
+pub mod manhattan;
+pub mod ocr;
+pub mod parse;
+pub mod point;
+pub mod render;
+pub mod search;
+
 use rusttype::Vector;
 type V = Vector<i32>;
 
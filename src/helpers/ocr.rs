@@ -0,0 +1,247 @@
+//! Reads the block letters that some puzzles draw as their answer.
+//!
+//! Two fonts are known: 4x6 letters, e.g. 2022 day 10, and 6x10 letters, e.g. 2018 day 10.
+//! Letters are separated by at least one dark column, so their exact spacing doesn't matter.
+use crate::bitmap::Bitmap;
+use std::fmt;
+
+const SMALL_HEIGHT: usize = 6;
+const LARGE_HEIGHT: usize = 10;
+
+const SMALL_GLYPHS: &[(char, &str)] = &[
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+const LARGE_GLYPHS: &[(char, &str)] = &[
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OcrError {
+    /// Text has to be 6 (small font) or 10 (large font) pixels high, not counting blank rows.
+    UnsupportedHeight(usize),
+    UnknownGlyph {
+        /// Position of the glyph in the text, starting at 0.
+        index: usize,
+        bitmap: Bitmap,
+    },
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OcrError::UnsupportedHeight(height) => write!(
+                f,
+                "text is {} pixels high, expected {} or {}",
+                height, SMALL_HEIGHT, LARGE_HEIGHT
+            ),
+            OcrError::UnknownGlyph { index, bitmap } => {
+                write!(f, "unknown glyph at position {}:\n{}", index, bitmap)
+            }
+        }
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+/// Recognizes the letters in `bitmap`. Blank rows and columns around the text are ignored.
+pub fn recognize(bitmap: &Bitmap) -> Result<String, OcrError> {
+    let lit_rows: Vec<usize> = (0..bitmap.height())
+        .filter(|&y| (0..bitmap.width()).any(|x| bitmap.get(x, y)))
+        .collect();
+    let (top, height) = match (lit_rows.first(), lit_rows.last()) {
+        (Some(&top), Some(&bottom)) => (top, bottom - top + 1),
+        _ => return Ok(String::new()),
+    };
+    let glyphs = match height {
+        SMALL_HEIGHT => SMALL_GLYPHS,
+        LARGE_HEIGHT => LARGE_GLYPHS,
+        height => return Err(OcrError::UnsupportedHeight(height)),
+    };
+
+    let lit_column = |x: usize| (top..top + height).any(|y| bitmap.get(x, y));
+    let mut text = String::new();
+    let mut x = 0;
+    while x < bitmap.width() {
+        if !lit_column(x) {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        while x < bitmap.width() && lit_column(x) {
+            x += 1;
+        }
+
+        let glyph = Bitmap::from_fn(x - start, height, |dx, dy| bitmap.get(start + dx, top + dy));
+        let pattern = glyph.to_string();
+        match glyphs.iter().find(|(_, known)| *known == pattern) {
+            Some((letter, _)) => text.push(*letter),
+            None => {
+                return Err(OcrError::UnknownGlyph {
+                    index: text.len(),
+                    bitmap: glyph,
+                })
+            }
+        }
+    }
+
+    Ok(text)
+}
+
+/// Recognizes the letters in `#`/`.` rows, e.g. a screen printed by a solution.
+pub fn recognize_str(s: &str) -> Result<String, String> {
+    let bitmap: Bitmap = s.parse()?;
+    recognize(&bitmap).map_err(|e| e.to_string())
+}
+
+/// Recognizes the letters in rows of pixels. Rows may differ in length.
+pub fn recognize_rows<R: AsRef<[bool]>>(rows: &[R]) -> Result<String, OcrError> {
+    let width = rows.iter().map(|row| row.as_ref().len()).max().unwrap_or(0);
+    let bitmap = Bitmap::from_fn(width, rows.len(), |x, y| {
+        rows[y].as_ref().get(x).copied().unwrap_or(false)
+    });
+    recognize(&bitmap)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recognize_small() {
+        let screen = "
+###..#....#..#.####.
+#..#.#....#..#.#....
+#..#.#....####.###..
+###..#....#..#.#....
+#.#..#....#..#.#....
+#..#.####.#..#.####.";
+        assert_eq!(recognize_str(screen), Ok("RLHE".to_string()));
+    }
+
+    #[test]
+    fn test_recognize_large() {
+        let screen = "
+#####...######..#....#..######
+#....#..#.......#....#.......#
+#....#..#.......#....#.......#
+#....#..#.......#....#......#.
+#####...#####...######.....#..
+#..#....#.......#....#....#...
+#...#...#.......#....#...#....
+#...#...#.......#....#..#.....
+#....#..#.......#....#..#.....
+#....#..######..#....#..######";
+        assert_eq!(recognize_str(screen), Ok("REHZ".to_string()));
+    }
+
+    #[test]
+    fn test_recognize_all_glyphs() {
+        // every glyph can be read back, also when padded with blank rows and columns.
+        for (letter, glyph) in SMALL_GLYPHS.iter().chain(LARGE_GLYPHS) {
+            let glyph: Bitmap = glyph.parse().unwrap();
+            let padded = Bitmap::from_fn(glyph.width() + 4, glyph.height() + 2, |x, y| {
+                x >= 2 && y >= 1 && glyph.get(x - 2, y - 1)
+            });
+            assert_eq!(recognize(&padded), Ok(letter.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_recognize_rows() {
+        let rows: Vec<Vec<bool>> = SMALL_GLYPHS[0]
+            .1
+            .lines()
+            .map(|row| row.chars().map(|c| c == '#').collect())
+            .collect();
+        assert_eq!(recognize_rows(&rows), Ok("A".to_string()));
+    }
+
+    #[test]
+    fn test_recognize_errors() {
+        let error = recognize_str("####.#\n#..#.#\n#..#.#\n####.#\n#..#.#\n#..#.#").unwrap_err();
+        assert_eq!(
+            error,
+            "unknown glyph at position 0:\n####\n#..#\n#..#\n####\n#..#\n#..#"
+        );
+
+        let bitmap: Bitmap = "#\n#\n#".parse().unwrap();
+        assert_eq!(recognize(&bitmap), Err(OcrError::UnsupportedHeight(3)));
+    }
+}
@@ -0,0 +1,409 @@
+//! Parsing for the shapes most inputs come in: numbers scattered through text, paragraphs
+//! separated by blank lines, and one record per line.
+//!
+//! Errors point at the line and column that couldn't be parsed, so a malformed input fails
+//! with a readable message instead of a bare `unwrap` panic:
+//!
+//! ```
+//! use advent_of_code::helpers::parse::{extract, parse_lines};
+//!
+//! let input = "move 1 from 2 to 1\nmove x from 1 to 3";
+//! let error = parse_lines(input, |line| extract::<(u32, u32, u32)>("move {} from {} to {}", line))
+//!     .unwrap_err();
+//! assert_eq!(
+//!     error.to_string(),
+//!     "line 2, column 6: invalid value `x`: invalid digit found in string\n  move x from 1 to 3\n       ^"
+//! );
+//! ```
+use std::fmt;
+use std::num::{ParseFloatError, ParseIntError};
+use std::str::FromStr;
+
+/// Why a single line couldn't be parsed. Columns count characters and start at 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LineError {
+    pub column: usize,
+    pub message: String,
+}
+
+impl LineError {
+    pub fn at(column: usize, message: impl Into<String>) -> LineError {
+        LineError {
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<String> for LineError {
+    fn from(message: String) -> Self {
+        LineError::at(1, message)
+    }
+}
+
+impl From<&str> for LineError {
+    fn from(message: &str) -> Self {
+        LineError::at(1, message)
+    }
+}
+
+impl From<ParseIntError> for LineError {
+    fn from(e: ParseIntError) -> Self {
+        LineError::at(1, e.to_string())
+    }
+}
+
+impl From<ParseFloatError> for LineError {
+    fn from(e: ParseFloatError) -> Self {
+        LineError::at(1, e.to_string())
+    }
+}
+
+/// A [`LineError`] together with the line it happened on.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+    pub text: String,
+}
+
+impl ParseError {
+    /// The error with its line number counted from `first_line` instead of 1, e.g. for the
+    /// lines of a paragraph. Like `line`, `first_line` counts from 1, 0 is treated as 1.
+    pub fn starting_at(mut self, first_line: usize) -> ParseError {
+        self.line += first_line.saturating_sub(1);
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}\n  {}\n  {:>width$}",
+            self.line,
+            self.column,
+            self.message,
+            self.text,
+            "^",
+            width = self.column
+        )
+    }
+}
+
+/// Shows the same message as [`Display`](fmt::Display), so that `unwrap` prints it readably.
+impl fmt::Debug for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses every line of `input` with `parse`, stopping at the first line that fails.
+/// Trailing blank lines are ignored.
+pub fn parse_lines<T, E, F>(input: &str, mut parse: F) -> Result<Vec<T>, ParseError>
+where
+    E: Into<LineError>,
+    F: FnMut(&str) -> Result<T, E>,
+{
+    input
+        .trim_end()
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            parse(line).map_err(|e| {
+                let LineError { column, message } = e.into();
+                ParseError {
+                    line: i + 1,
+                    column,
+                    message,
+                    text: line.to_string(),
+                }
+            })
+        })
+        .collect()
+}
+
+/// Every integer in `text`, e.g. `[-2, 15, 3]` in `"x=-2, y=15: 3"`. A `-` is only a sign
+/// if it doesn't follow a digit, so `"2-4"` is `[2, 4]`. A number that doesn't fit in `T`,
+/// like `-1` for a `u32`, is an error pointing at its line and column.
+pub fn ints<T: FromStr>(text: &str) -> impl Iterator<Item = Result<T, ParseError>> + '_
+where
+    T::Err: fmt::Display,
+{
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        while i < bytes.len() {
+            let start = i;
+            let signed = bytes[i] == b'-' && (i == 0 || !bytes[i - 1].is_ascii_digit());
+            let digits = if signed { i + 1 } else { i };
+            let end = digits
+                + bytes[digits.min(bytes.len())..]
+                    .iter()
+                    .take_while(|b| b.is_ascii_digit())
+                    .count();
+
+            if end == digits {
+                i += 1;
+                continue;
+            }
+            i = end;
+            return Some(text[start..end].parse().map_err(|e: T::Err| {
+                let line_start = text[..start].rfind('\n').map_or(0, |n| n + 1);
+                let line_end = text[start..].find('\n').map_or(text.len(), |n| start + n);
+                ParseError {
+                    line: text[..start].matches('\n').count() + 1,
+                    column: text[line_start..start].chars().count() + 1,
+                    message: format!("invalid value `{}`: {}", &text[start..end], e),
+                    text: text[line_start..line_end]
+                        .trim_end_matches('\r')
+                        .to_string(),
+                }
+            }));
+        }
+        None
+    })
+}
+
+/// The blocks of lines separated by blank lines, without their line breaks at the end.
+/// Leading whitespace of the first line is kept, since it can matter, like in 2022 day 05.
+pub fn paragraphs(input: &str) -> impl Iterator<Item = &str> {
+    let mut rest = input;
+    std::iter::from_fn(move || {
+        // skip blank lines.
+        while let Some(line) = rest.split_inclusive('\n').next() {
+            if !line.trim().is_empty() {
+                break;
+            }
+            rest = &rest[line.len()..];
+        }
+        if rest.is_empty() {
+            return None;
+        }
+
+        let mut end = 0;
+        for line in rest.split_inclusive('\n') {
+            if line.trim().is_empty() {
+                break;
+            }
+            end += line.len();
+        }
+        let paragraph = rest[..end].trim_end_matches(['\n', '\r']);
+        rest = &rest[end..];
+        Some(paragraph)
+    })
+}
+
+/// Values that can be built from the captures of a pattern, see [`extract`].
+pub trait FromCaptures: Sized {
+    fn from_captures(captures: &[(usize, &str)]) -> Result<Self, LineError>;
+}
+
+fn parse_capture<T: FromStr>(&(column, value): &(usize, &str)) -> Result<T, LineError>
+where
+    T::Err: fmt::Display,
+{
+    value.parse().map_err(|e: T::Err| {
+        if value.is_empty() {
+            LineError::at(column, "missing value")
+        } else {
+            LineError::at(column, format!("invalid value `{}`: {}", value, e))
+        }
+    })
+}
+
+macro_rules! impl_from_captures {
+    ($count:literal: $($name:ident),+) => {
+        impl<$($name: FromStr),+> FromCaptures for ($($name,)+)
+        where
+            $($name::Err: fmt::Display),+
+        {
+            fn from_captures(captures: &[(usize, &str)]) -> Result<Self, LineError> {
+                if captures.len() != $count {
+                    return Err(LineError::at(
+                        1,
+                        format!("the pattern has {} placeholders, expected {}", captures.len(), $count),
+                    ));
+                }
+                let mut captures = captures.iter();
+                Ok(($(parse_capture::<$name>(captures.next().unwrap())?,)+))
+            }
+        }
+    };
+}
+
+impl_from_captures!(1: A);
+impl_from_captures!(2: A, B);
+impl_from_captures!(3: A, B, C);
+impl_from_captures!(4: A, B, C, D);
+impl_from_captures!(5: A, B, C, D, E);
+impl_from_captures!(6: A, B, C, D, E, F);
+
+/// The text of every `{}` in `pattern`, with the column it starts at. The rest of the pattern
+/// has to match `line` exactly. A `{}` ends where the text after it in the pattern is found
+/// first, or at the end of the line, so two `{}` need some text between them.
+pub fn captures<'a>(pattern: &str, line: &'a str) -> Result<Vec<(usize, &'a str)>, LineError> {
+    let column = |rest: &str| line[..line.len() - rest.len()].chars().count() + 1;
+    let mut pieces = pattern.split("{}");
+    let prefix = pieces.next().unwrap_or_default();
+
+    let mut rest = line.strip_prefix(prefix).ok_or_else(|| {
+        let matching = line
+            .chars()
+            .zip(prefix.chars())
+            .take_while(|(a, b)| a == b)
+            .count();
+        LineError::at(matching + 1, format!("expected `{}`", prefix))
+    })?;
+
+    let mut captures = Vec::new();
+    for literal in pieces {
+        let end = if literal.is_empty() {
+            Some(rest.len())
+        } else {
+            rest.find(literal)
+        };
+        let end = end.ok_or_else(|| {
+            LineError::at(
+                column(rest),
+                format!("expected a value followed by `{}`", literal),
+            )
+        })?;
+        captures.push((column(rest), &rest[..end]));
+        rest = &rest[end + literal.len()..];
+    }
+
+    if !rest.is_empty() {
+        return Err(LineError::at(
+            column(rest),
+            format!("unexpected `{}` at the end of the line", rest),
+        ));
+    }
+    Ok(captures)
+}
+
+/// Parses the `{}` placeholders of `pattern` in `line` into a tuple, e.g.
+/// `extract::<(char, i32)>("{} {}", "R 4")` is `('R', 4)`.
+pub fn extract<T: FromCaptures>(pattern: &str, line: &str) -> Result<T, LineError> {
+    T::from_captures(&captures(pattern, line)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ints() {
+        let all = |text| ints::<i64>(text).collect::<Result<Vec<_>, _>>().unwrap();
+        let line = "Sensor at x=2, y=-18: closest beacon is at x=-2, y=15";
+        assert_eq!(all(line), [2, -18, -2, 15]);
+        assert_eq!(all("2-4,6-8"), [2, 4, 6, 8]);
+        assert_eq!(all("-1 --2 a-3-"), [-1, -2, -3]);
+        assert_eq!(ints::<i32>("no numbers - here").count(), 0);
+    }
+
+    #[test]
+    fn test_ints_errors() {
+        // numbers that don't fit are errors, not skipped.
+        let values: Vec<_> = ints::<u8>("7\n1 300\r\n-1").collect();
+        assert_eq!(values[0], Ok(7));
+        assert_eq!(values[1], Ok(1));
+        assert_eq!(
+            format!("{:?}", values[2].as_ref().unwrap_err()),
+            "line 2, column 3: invalid value `300`: number too large to fit in target type\n  1 300\n    ^"
+        );
+        let error = values[3].as_ref().unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let input = "    [D]\n[N] [C]\n\nmove 1 from 2 to 1\n\n\n\nlast\r\n\r\n";
+        assert_eq!(
+            paragraphs(input).collect::<Vec<_>>(),
+            ["    [D]\n[N] [C]", "move 1 from 2 to 1", "last"]
+        );
+        assert_eq!(paragraphs("\n\na\nb").collect::<Vec<_>>(), ["a\nb"]);
+        assert_eq!(paragraphs("\n \n").count(), 0);
+    }
+
+    #[test]
+    fn test_extract() {
+        assert_eq!(extract::<(char, i32)>("{} {}", "R 4"), Ok(('R', 4)));
+        assert_eq!(
+            extract::<(i32, i32, i32, i32)>(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                "Sensor at x=2, y=18: closest beacon is at x=-2, y=15"
+            ),
+            Ok((2, 18, -2, 15))
+        );
+        assert_eq!(captures("Monkey {}:", "Monkey 3:"), Ok(vec![(8, "3")]));
+        assert_eq!(
+            extract::<(String, u32)>("{} -> {}", "ab -> 7"),
+            Ok(("ab".to_string(), 7))
+        );
+    }
+
+    #[test]
+    fn test_extract_errors() {
+        let pattern = "move {} from {} to {}";
+        let error = |line| extract::<(u32, u32, u32)>(pattern, line).unwrap_err();
+
+        assert_eq!(
+            error("mvoe 1 from 2 to 3"),
+            LineError::at(2, "expected `move `")
+        );
+        assert_eq!(
+            error("move 1 to 3"),
+            LineError::at(6, "expected a value followed by ` from `")
+        );
+        assert_eq!(
+            error("move 1 from 2 to x"),
+            LineError::at(18, "invalid value `x`: invalid digit found in string")
+        );
+        assert_eq!(
+            error("move 1 from  to 3"),
+            LineError::at(13, "missing value")
+        );
+        assert_eq!(
+            error("move 1 from 2 to -3").message,
+            "invalid value `-3`: invalid digit found in string"
+        );
+        assert_eq!(
+            extract::<(u32, u32)>(pattern, "move 1 from 2 to 3"),
+            Err(LineError::at(
+                1,
+                "the pattern has 3 placeholders, expected 2"
+            ))
+        );
+        assert_eq!(
+            extract::<(u32,)>("{}!", "1!?"),
+            Err(LineError::at(3, "unexpected `?` at the end of the line"))
+        );
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(
+            parse_lines("1\n2\n3\n\n", str::parse::<u32>),
+            Ok(vec![1, 2, 3])
+        );
+
+        let error = parse_lines("1\n2\nthree", str::parse::<u32>).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.text, "three");
+        assert_eq!(
+            format!("{:?}", error.starting_at(5)),
+            "line 7, column 1: invalid digit found in string\n  three\n  ^"
+        );
+    }
+
+    #[test]
+    fn test_starting_at_zero() {
+        let error = parse_lines("x", str::parse::<u32>).unwrap_err();
+        assert_eq!(error.starting_at(0).line, 1);
+    }
+}
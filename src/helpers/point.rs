@@ -0,0 +1,312 @@
+//! Points with any number of dimensions, for puzzles in 3D like the lava droplet of 2022 day 18,
+//! or in 4D like the hypercubes of 2020 day 17. 2D maps keep using `rusttype::Point`.
+//!
+//! ```
+//! use advent_of_code::helpers::point::{Bounds, Point3};
+//!
+//! let cube: Point3 = "2,2,2".parse().unwrap();
+//! assert_eq!(cube.face_neighbours().count(), 6);
+//! assert_eq!(cube.neighbours().count(), 26);
+//!
+//! let bounds = Bounds::new(Point3::origin(), Point3::new([3, 3, 3]));
+//! assert_eq!(bounds.flood_fill(Point3::origin(), |p| p != cube).len(), 63);
+//! ```
+use super::search::bfs_all;
+use std::collections::HashSet;
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// A point with `N` integer coordinates.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<const N: usize>(pub [i32; N]);
+
+pub type Point3 = Point<3>;
+pub type Point4 = Point<4>;
+
+impl<const N: usize> Point<N> {
+    pub fn new(coordinates: [i32; N]) -> Point<N> {
+        Point(coordinates)
+    }
+
+    pub fn origin() -> Point<N> {
+        Point([0; N])
+    }
+
+    /// The point that is 1 along axis `axis` and 0 along the others.
+    pub fn unit(axis: usize) -> Point<N> {
+        let mut unit = Point::origin();
+        unit[axis] = 1;
+        unit
+    }
+
+    pub fn manhattan(&self, other: &Point<N>) -> i32 {
+        (0..N).map(|i| (self[i] - other[i]).abs()).sum()
+    }
+
+    /// The points that share a face with this one, 2 per dimension: 6 in 3D.
+    pub fn face_neighbours(self) -> impl Iterator<Item = Point<N>> {
+        (0..N).flat_map(move |axis| [self - Point::unit(axis), self + Point::unit(axis)])
+    }
+
+    /// The points that share a face, an edge or a corner with this one, `3^N - 1`: 26 in 3D.
+    pub fn neighbours(self) -> impl Iterator<Item = Point<N>> {
+        let count = 3usize.pow(N as u32);
+        (0..count)
+            .filter(move |&i| i != count / 2)
+            .map(move |mut i| {
+                let mut offset = Point::origin();
+                for axis in 0..N {
+                    offset[axis] = (i % 3) as i32 - 1;
+                    i /= 3;
+                }
+                self + offset
+            })
+    }
+}
+
+impl<const N: usize> Index<usize> for Point<N> {
+    type Output = i32;
+
+    fn index(&self, axis: usize) -> &i32 {
+        &self.0[axis]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Point<N> {
+    fn index_mut(&mut self, axis: usize) -> &mut i32 {
+        &mut self.0[axis]
+    }
+}
+
+impl<const N: usize> Add for Point<N> {
+    type Output = Point<N>;
+
+    fn add(self, other: Point<N>) -> Point<N> {
+        Point(std::array::from_fn(|i| self[i] + other[i]))
+    }
+}
+
+impl<const N: usize> Sub for Point<N> {
+    type Output = Point<N>;
+
+    fn sub(self, other: Point<N>) -> Point<N> {
+        Point(std::array::from_fn(|i| self[i] - other[i]))
+    }
+}
+
+impl<const N: usize> AddAssign for Point<N> {
+    fn add_assign(&mut self, other: Point<N>) {
+        *self = *self + other;
+    }
+}
+
+impl<const N: usize> SubAssign for Point<N> {
+    fn sub_assign(&mut self, other: Point<N>) {
+        *self = *self - other;
+    }
+}
+
+impl<const N: usize> Neg for Point<N> {
+    type Output = Point<N>;
+
+    fn neg(self) -> Point<N> {
+        Point(self.0.map(|c| -c))
+    }
+}
+
+impl<const N: usize> Mul<i32> for Point<N> {
+    type Output = Point<N>;
+
+    fn mul(self, factor: i32) -> Point<N> {
+        Point(self.0.map(|c| c * factor))
+    }
+}
+
+impl<const N: usize> fmt::Display for Point<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, c) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> fmt::Debug for Point<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({})", self)
+    }
+}
+
+/// Parses comma separated coordinates like `1,-2,3`, the way puzzles usually list them.
+impl<const N: usize> FromStr for Point<N> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coordinates: Vec<i32> = s
+            .split(',')
+            .map(|c| {
+                c.trim()
+                    .parse()
+                    .map_err(|e| format!("invalid coordinate `{}`: {}", c, e))
+            })
+            .collect::<Result<_, _>>()?;
+        let count = coordinates.len();
+        coordinates
+            .try_into()
+            .map(Point)
+            .map_err(|_| format!("expected {} coordinates, found {} in `{}`", N, count, s))
+    }
+}
+
+/// The points between two corners, including both.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bounds<const N: usize> {
+    pub min: Point<N>,
+    pub max: Point<N>,
+}
+
+impl<const N: usize> Bounds<N> {
+    pub fn new(min: Point<N>, max: Point<N>) -> Bounds<N> {
+        Bounds { min, max }
+    }
+
+    /// The smallest bounds that contain all `points`, `None` if there are none.
+    pub fn around(points: impl IntoIterator<Item = Point<N>>) -> Option<Bounds<N>> {
+        points.into_iter().fold(None, |bounds, p| {
+            Some(match bounds {
+                None => Bounds::new(p, p),
+                Some(Bounds { min, max }) => Bounds::new(
+                    Point(std::array::from_fn(|i| min[i].min(p[i]))),
+                    Point(std::array::from_fn(|i| max[i].max(p[i]))),
+                ),
+            })
+        })
+    }
+
+    pub fn contains(&self, p: Point<N>) -> bool {
+        (0..N).all(|i| self.min[i] <= p[i] && p[i] <= self.max[i])
+    }
+
+    /// The bounds with `by` more room on every side.
+    pub fn grow(&self, by: i32) -> Bounds<N> {
+        let by = Point([by; N]);
+        Bounds::new(self.min - by, self.max + by)
+    }
+
+    /// The number of points within the bounds.
+    pub fn volume(&self) -> u64 {
+        (0..N)
+            .map(|i| (self.max[i] - self.min[i] + 1).max(0) as u64)
+            .product()
+    }
+
+    /// The points reachable from `start` through face neighbours within the bounds,
+    /// stepping only onto points where `passable` is true. Includes `start`.
+    pub fn flood_fill(
+        &self,
+        start: Point<N>,
+        mut passable: impl FnMut(Point<N>) -> bool,
+    ) -> HashSet<Point<N>> {
+        if !self.contains(start) {
+            return HashSet::new();
+        }
+        let successors = |p: &Point<N>| {
+            p.face_neighbours()
+                .filter(|&next| self.contains(next) && passable(next))
+                .collect::<Vec<_>>()
+        };
+        bfs_all([start], successors)
+            .iter()
+            .map(|(&p, _)| p)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point3::new([1, -2, 3]);
+        let b = Point3::new([4, 0, -1]);
+        assert_eq!(a + b, Point([5, -2, 2]));
+        assert_eq!(a - b, Point([-3, -2, 4]));
+        assert_eq!(-a, Point([-1, 2, -3]));
+        assert_eq!(a * 2, Point([2, -4, 6]));
+        assert_eq!(a.manhattan(&b), 9);
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+        c[2] = 7;
+        assert_eq!(c[2], 7);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("1,-2,3".parse(), Ok(Point3::new([1, -2, 3])));
+        assert_eq!("1, 2, 3, 4".parse(), Ok(Point4::new([1, 2, 3, 4])));
+        assert_eq!(
+            "1,2".parse::<Point3>(),
+            Err("expected 3 coordinates, found 2 in `1,2`".to_string())
+        );
+        assert!("1,x,3".parse::<Point3>().is_err());
+        assert_eq!(Point3::new([1, -2, 3]).to_string(), "1,-2,3");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let p = Point3::new([5, 5, 5]);
+        let faces: HashSet<_> = p.face_neighbours().collect();
+        assert_eq!(faces.len(), 6);
+        assert!(faces.iter().all(|n| n.manhattan(&p) == 1));
+
+        let all: HashSet<_> = p.neighbours().collect();
+        assert_eq!(all.len(), 26);
+        assert!(!all.contains(&p));
+        assert!(faces.is_subset(&all));
+
+        assert_eq!(Point4::origin().face_neighbours().count(), 8);
+        assert_eq!(Point4::origin().neighbours().count(), 80);
+        assert_eq!(
+            Point::<2>::origin()
+                .neighbours()
+                .collect::<HashSet<_>>()
+                .len(),
+            8
+        );
+    }
+
+    #[test]
+    fn test_bounds() {
+        let points = ["2,2,2", "1,2,2", "3,2,2", "2,1,2", "2,3,5"].map(|p| p.parse().unwrap());
+        let bounds = Bounds::<3>::around(points).unwrap();
+        assert_eq!(bounds, Bounds::new(Point([1, 1, 2]), Point([3, 3, 5])));
+        assert_eq!(bounds.volume(), 36);
+        assert!(bounds.contains(Point([3, 1, 5])) && !bounds.contains(Point([0, 1, 5])));
+        assert_eq!(bounds.grow(1).volume(), 5 * 5 * 6);
+        assert_eq!(Bounds::<3>::around([]), None);
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        // the surface of a 3x3x3 cube, with a hollow center.
+        let bounds = Bounds::new(Point3::origin(), Point3::new([2, 2, 2]));
+        let center = Point3::new([1, 1, 1]);
+        let shell = |p: Point3| p != center;
+
+        let outside = bounds.grow(1);
+        let air = outside.flood_fill(Point3::new([-1, -1, -1]), |p| !bounds.contains(p));
+        assert_eq!(air.len() as u64, outside.volume() - bounds.volume());
+
+        assert_eq!(bounds.flood_fill(center, |p| !shell(p)).len(), 1);
+        assert_eq!(bounds.flood_fill(Point3::origin(), shell).len(), 26);
+        assert!(bounds.flood_fill(Point3::new([5, 5, 5]), shell).is_empty());
+    }
+}
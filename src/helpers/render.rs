@@ -0,0 +1,500 @@
+//! Pictures of grids and point sets, for looking at what a simulation is doing.
+//!
+//! An [`Image`] is built from a [`Grid`], a [`SparseGrid`], a set of points or any area
+//! with a closure that picks the color of every cell. It can be printed to a terminal
+//! that supports 24-bit colors, or saved as a PPM or PNG file. [`Frames`] saves one
+//! numbered image per step, which e.g. `ffmpeg` can stitch into an animation:
+//!
+//! ```sh
+//! ffmpeg -framerate 30 -i frames/frame_%05d.png sand.gif
+//! ```
+use super::grid::{Grid, SparseGrid};
+use crate::bitmap::Bitmap;
+use crate::ANSI_RESET;
+use rusttype::Point;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+type P = Point<i32>;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GRAY: Rgb = Rgb(128, 128, 128);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(0, 153, 0);
+    pub const BLUE: Rgb = Rgb(38, 139, 210);
+    pub const YELLOW: Rgb = Rgb(255, 255, 102);
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Image {
+        Image {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, f: impl Fn(usize, usize) -> Rgb) -> Image {
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// One pixel for every point from `min` to `max`, inclusive.
+    /// Use a fixed area for [`Frames`] of a growing map, so that all frames have the same size.
+    pub fn from_area(min: P, max: P, f: impl Fn(P) -> Rgb) -> Image {
+        let width = (max.x - min.x + 1).max(0) as usize;
+        let height = (max.y - min.y + 1).max(0) as usize;
+        Image::from_fn(width, height, |x, y| {
+            f(Point {
+                x: min.x + x as i32,
+                y: min.y + y as i32,
+            })
+        })
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, color: impl Fn(&T) -> Rgb) -> Image {
+        Image::from_fn(grid.width(), grid.height(), |x, y| {
+            color(
+                &grid[Point {
+                    x: x as i32,
+                    y: y as i32,
+                }],
+            )
+        })
+    }
+
+    /// The area within the grid's bounds. `color` gets `None` for empty cells.
+    pub fn from_sparse<T>(grid: &SparseGrid<T>, color: impl Fn(Option<&T>) -> Rgb) -> Image {
+        match grid.bounds() {
+            Some((min, max)) => Image::from_area(min, max, |p| color(grid.get(p))),
+            None => Image::new(0, 0, Rgb::BLACK),
+        }
+    }
+
+    /// The bounding box of `points`, with the points in `color` on `background`.
+    pub fn from_points(points: &[P], color: Rgb, background: Rgb) -> Image {
+        let mut grid = SparseGrid::new();
+        for &p in points {
+            grid.insert(p, ());
+        }
+        Image::from_sparse(&grid, |cell| cell.map_or(background, |_| color))
+    }
+
+    pub fn from_bitmap(bitmap: &Bitmap, lit: Rgb, dark: Rgb) -> Image {
+        Image::from_fn(bitmap.width(), bitmap.height(), |x, y| {
+            if bitmap.get(x, y) {
+                lit
+            } else {
+                dark
+            }
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns `None` for pixels outside of the image.
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, color: Rgb) {
+        assert!(
+            x < self.width && y < self.height,
+            "pixel ({}, {}) is outside of a {}x{} image",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+        self.pixels[y * self.width + x] = color;
+    }
+
+    /// Enlarges every pixel to a `factor`x`factor` square, since one pixel per cell is hard to see.
+    pub fn scale(&self, factor: usize) -> Image {
+        Image::from_fn(self.width * factor, self.height * factor, |x, y| {
+            self.pixels[y / factor * self.width + x / factor]
+        })
+    }
+
+    /// Draws two rows of pixels per line of text using `▀` with 24-bit colors.
+    pub fn to_ansi(&self) -> String {
+        let mut out = String::new();
+        for y in (0..self.height).step_by(2) {
+            for x in 0..self.width {
+                let Rgb(r, g, b) = self.pixels[y * self.width + x];
+                write!(out, "\x1b[38;2;{};{};{}m", r, g, b).unwrap();
+                match self.get(x, y + 1) {
+                    Some(Rgb(r, g, b)) => write!(out, "\x1b[48;2;{};{};{}m▀", r, g, b).unwrap(),
+                    None => out.push('▀'),
+                }
+            }
+            writeln!(out, "{}", ANSI_RESET).unwrap();
+        }
+        out
+    }
+
+    /// Binary PPM (`P6`), which many image viewers can open.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        for &Rgb(r, g, b) in &self.pixels {
+            out.extend([r, g, b]);
+        }
+        out
+    }
+
+    /// An uncompressed 8-bit RGB PNG.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut scanlines = Vec::with_capacity((self.width * 3 + 1) * self.height);
+        for row in self.pixels.chunks(self.width.max(1)).take(self.height) {
+            // filter type 0: the scanline is stored as it is.
+            scanlines.push(0);
+            for &Rgb(r, g, b) in row {
+                scanlines.extend([r, g, b]);
+            }
+        }
+
+        let mut header = Vec::with_capacity(13);
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // bit depth 8, color type 2 (RGB), default compression, filtering and no interlacing.
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut out, b"IHDR", &header);
+        png_chunk(&mut out, b"IDAT", &zlib_stored(&scanlines));
+        png_chunk(&mut out, b"IEND", &[]);
+        out
+    }
+
+    /// Saves the image in the format given by the extension of `path`, `.png` or `.ppm`.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let format = ImageFormat::from_path(path).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{}: expected a .png or .ppm file", path.display()),
+            )
+        })?;
+        fs::write(path, self.encode(format))
+    }
+
+    pub fn encode(&self, format: ImageFormat) -> Vec<u8> {
+        match format {
+            ImageFormat::Ppm => self.to_ppm(),
+            ImageFormat::Png => self.to_png(),
+        }
+    }
+}
+
+/// Prints the cells of a grid as characters, each in its own color.
+pub fn ansi_chars<T>(grid: &Grid<T>, style: impl Fn(&T) -> (char, Rgb)) -> String {
+    let mut out = String::new();
+    for row in grid.rows() {
+        for cell in row {
+            let (c, Rgb(r, g, b)) = style(cell);
+            write!(out, "\x1b[38;2;{};{};{}m{}", r, g, b, c).unwrap();
+        }
+        writeln!(out, "{}", ANSI_RESET).unwrap();
+    }
+    out
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+}
+
+impl ImageFormat {
+    pub fn from_path(path: &Path) -> Option<ImageFormat> {
+        match path.extension()?.to_str()? {
+            "ppm" => Some(ImageFormat::Ppm),
+            "png" => Some(ImageFormat::Png),
+            _ => None,
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Png => "png",
+        }
+    }
+}
+
+/// Saves a sequence of images as `frame_00000.png`, `frame_00001.png`, ... in a directory.
+pub struct Frames {
+    dir: PathBuf,
+    format: ImageFormat,
+    count: usize,
+}
+
+impl Frames {
+    /// Creates `dir` if necessary and removes the frames of a previous run from it.
+    pub fn new(dir: impl Into<PathBuf>, format: ImageFormat) -> io::Result<Frames> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            let is_frame = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("frame_"));
+            if is_frame && ImageFormat::from_path(&path) == Some(format) {
+                fs::remove_file(path)?;
+            }
+        }
+        Ok(Frames {
+            dir,
+            format,
+            count: 0,
+        })
+    }
+
+    /// Saves the next frame and returns its path.
+    pub fn push(&mut self, image: &Image) -> io::Result<PathBuf> {
+        let path = self.dir.join(format!(
+            "frame_{:05}.{}",
+            self.count,
+            self.format.extension()
+        ));
+        fs::write(&path, image.encode(self.format))?;
+        self.count += 1;
+        Ok(path)
+    }
+
+    /// The number of frames saved so far.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+fn png_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+/// Wraps `data` in a zlib stream of uncompressed ("stored") deflate blocks.
+/// Images for debugging don't need to be small, so this saves implementing compression.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = u16::MAX as usize;
+
+    let mut out = Vec::with_capacity(data.len() + data.len() / MAX_BLOCK * 5 + 11);
+    // deflate with a 32K window, no preset dictionary. The check bits make the header a multiple of 31.
+    out.extend([0x78, 0x01]);
+
+    let mut blocks = data.chunks(MAX_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let is_last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        out.push(u8::from(is_last));
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut n = 0;
+    while n < 256 {
+        let mut c = n as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 == 1 {
+                0xedb8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[n] = c;
+        n += 1;
+    }
+    table
+};
+
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0, |crc, &byte| {
+        CRC_TABLE[((crc ^ u32::from(byte)) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+    let (mut a, mut b) = (1, 0);
+    // sums of up to 5552 bytes can't overflow before taking the modulo.
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += u32::from(byte);
+            b += a;
+        }
+        a %= MOD;
+        b %= MOD;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+        assert_eq!(adler32(&[]), 1);
+        assert_eq!(adler32(&[255; 100_000]), {
+            let (a, b) = (1..=100_000u64).fold((1u64, 0u64), |(a, b), _| {
+                let a = (a + 255) % 65521;
+                (a, (b + a) % 65521)
+            });
+            ((b << 16) | a) as u32
+        });
+    }
+
+    #[test]
+    fn test_zlib_stored() {
+        let data: Vec<u8> = (0..70_000).map(|i| i as u8).collect();
+        let stream = zlib_stored(&data);
+        assert_eq!(&stream[..2], [0x78, 0x01]);
+        assert_eq!((0x78 * 256 + 0x01) % 31, 0);
+
+        // a full block of 65535 bytes, followed by the final block with the rest.
+        assert_eq!(&stream[2..7], [0, 0xff, 0xff, 0, 0]);
+        assert_eq!(&stream[7..7 + 65535], &data[..65535]);
+        let last = 7 + 65535;
+        assert_eq!(stream[last], 1);
+        assert_eq!(
+            u16::from_le_bytes([stream[last + 1], stream[last + 2]]),
+            (70_000 - 65535) as u16
+        );
+        assert_eq!(&stream[last + 5..stream.len() - 4], &data[65535..]);
+        assert_eq!(&stream[stream.len() - 4..], adler32(&data).to_be_bytes());
+
+        assert_eq!(
+            zlib_stored(&[]),
+            [0x78, 0x01, 1, 0, 0, 0xff, 0xff, 0, 0, 0, 1]
+        );
+    }
+
+    #[test]
+    fn test_to_png() {
+        let mut image = Image::new(2, 1, Rgb::BLACK);
+        image.set(1, 0, Rgb(1, 2, 3));
+        let png = image.to_png();
+
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[8..16], b"\0\0\0\x0dIHDR");
+        assert_eq!(&png[16..29], [0, 0, 0, 2, 0, 0, 0, 1, 8, 2, 0, 0, 0]);
+        assert_eq!(&png[29..33], crc32(&png[12..29]).to_be_bytes());
+
+        let idat = &png[33..];
+        let len = u32::from_be_bytes(idat[..4].try_into().unwrap()) as usize;
+        assert_eq!(&idat[4..8], b"IDAT");
+        assert_eq!(
+            &idat[8..8 + len],
+            zlib_stored(&[0, 0, 0, 0, 1, 2, 3]).as_slice()
+        );
+        assert!(png.ends_with(b"\0\0\0\0IEND\xae\x42\x60\x82"));
+    }
+
+    #[test]
+    fn test_to_ppm() {
+        let image = Image::from_points(
+            &[Point { x: 3, y: 5 }, Point { x: 4, y: 6 }],
+            Rgb::WHITE,
+            Rgb::BLACK,
+        );
+        assert_eq!((image.width(), image.height()), (2, 2));
+        let mut expected = b"P6\n2 2\n255\n".to_vec();
+        expected.extend([255, 255, 255, 0, 0, 0, 0, 0, 0, 255, 255, 255]);
+        assert_eq!(image.to_ppm(), expected);
+    }
+
+    #[test]
+    fn test_from_grid() {
+        let grid: Grid<char> = "#.\n.#".parse().unwrap();
+        let image = Image::from_grid(&grid, |&c| if c == '#' { Rgb::RED } else { Rgb::BLACK });
+        assert_eq!(image.get(0, 0), Some(Rgb::RED));
+        assert_eq!(image.get(1, 0), Some(Rgb::BLACK));
+        assert_eq!(image.get(2, 0), None);
+
+        let scaled = image.scale(3);
+        assert_eq!((scaled.width(), scaled.height()), (6, 6));
+        assert_eq!(scaled.get(5, 5), Some(Rgb::RED));
+        assert_eq!(scaled.get(2, 3), Some(Rgb::BLACK));
+    }
+
+    #[test]
+    fn test_to_ansi() {
+        let image = Image::from_fn(1, 3, |_, y| Rgb(y as u8, 0, 0));
+        assert_eq!(
+            image.to_ansi(),
+            "\x1b[38;2;0;0;0m\x1b[48;2;1;0;0m▀\x1b[0m\n\x1b[38;2;2;0;0m▀\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn test_frames() {
+        let dir = std::env::temp_dir().join("aoc_render_test_frames");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("frame_00007.ppm"), "stale").unwrap();
+        fs::write(dir.join("notes.txt"), "kept").unwrap();
+
+        let mut frames = Frames::new(&dir, ImageFormat::Ppm).unwrap();
+        let image = Image::new(1, 1, Rgb::WHITE);
+        assert_eq!(frames.push(&image).unwrap(), dir.join("frame_00000.ppm"));
+        frames.push(&image).unwrap();
+        assert_eq!(frames.len(), 2);
+
+        let mut names: Vec<String> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        names.sort();
+        assert_eq!(names, ["frame_00000.ppm", "frame_00001.ppm", "notes.txt"]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,399 @@
+//! Shortest paths through graphs that are only described by closures.
+//!
+//! A state can be anything hashable: a point on a map, or a point together with the
+//! direction it was entered from. The searches ask for the successors of a state
+//! (with the cost of the step for [`dijkstra`] and [`astar`]) and stop at the first state
+//! that satisfies `is_goal`. All of them accept several start states.
+//!
+//! ```
+//! use advent_of_code::helpers::search::bfs;
+//!
+//! // the fewest steps of +1 or *2 from 1 to 10.
+//! let found = bfs([1], |&n| [n + 1, n * 2], |&n| n == 10).unwrap();
+//! assert_eq!(found.cost, 4);
+//! assert_eq!(found.path(), [1, 2, 4, 5, 10]);
+//! ```
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Every state a search reached, with its lowest known cost and the state it was reached from.
+#[derive(Clone, Debug)]
+pub struct Explored<S, C> {
+    nodes: HashMap<S, (C, Option<S>)>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Explored<S, C> {
+    fn new() -> Explored<S, C> {
+        Explored {
+            nodes: HashMap::new(),
+        }
+    }
+
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.nodes.get(state).map(|&(cost, _)| cost)
+    }
+
+    /// The state `state` was reached from, `None` for start states and unreached states.
+    pub fn predecessor(&self, state: &S) -> Option<&S> {
+        self.nodes.get(state)?.1.as_ref()
+    }
+
+    /// The state every reached state, except for the start states, was reached from.
+    pub fn predecessors(&self) -> HashMap<S, S> {
+        self.nodes
+            .iter()
+            .filter_map(|(state, (_, predecessor))| {
+                predecessor
+                    .as_ref()
+                    .map(|predecessor| (state.clone(), predecessor.clone()))
+            })
+            .collect()
+    }
+
+    /// The states from a start state to `state`, both included.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        let mut path = vec![state.clone()];
+        let mut current = self.nodes.get(state)?;
+        while let Some(previous) = &current.1 {
+            path.push(previous.clone());
+            current = &self.nodes[previous];
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn contains(&self, state: &S) -> bool {
+        self.nodes.contains_key(state)
+    }
+
+    /// The number of reached states.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Every reached state with its cost, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&S, C)> {
+        self.nodes.iter().map(|(state, &(cost, _))| (state, cost))
+    }
+}
+
+/// A goal reached by a search.
+#[derive(Clone, Debug)]
+pub struct Found<S, C> {
+    pub goal: S,
+    pub cost: C,
+    /// Everything the search reached until it found the goal.
+    pub explored: Explored<S, C>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Found<S, C> {
+    /// The states from a start state to the goal, both included.
+    pub fn path(&self) -> Vec<S> {
+        self.explored.path_to(&self.goal).unwrap()
+    }
+}
+
+/// Breadth-first search for graphs where every step costs 1. The cost is the number of steps.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let (explored, goal) = bfs_until(starts, successors, is_goal);
+    goal.map(|goal| Found {
+        cost: explored.cost(&goal).unwrap(),
+        goal,
+        explored,
+    })
+}
+
+/// Reaches every state that can be reached from the start states, see [`bfs`].
+pub fn bfs_all<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+) -> Explored<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    bfs_until(starts, successors, |_| false).0
+}
+
+fn bfs_until<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> (Explored<S, usize>, Option<S>)
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut explored = Explored::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = explored.nodes.entry(start.clone()) {
+            entry.insert((0, None));
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((state, cost)) = queue.pop_front() {
+        if is_goal(&state) {
+            return (explored, Some(state));
+        }
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = explored.nodes.entry(next.clone()) {
+                entry.insert((cost + 1, Some(state.clone())));
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+
+    (explored, None)
+}
+
+/// Dijkstra's algorithm for graphs whose steps have different, non-negative costs.
+/// `successors` returns the next states together with the cost of stepping to them.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// Reaches every state that can be reached from the start states, see [`dijkstra`].
+pub fn dijkstra_all<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+) -> Explored<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar_until(starts, successors, |_| C::default(), |_| false).0
+}
+
+/// A* search: Dijkstra's algorithm that looks at states closer to a goal first.
+/// `heuristic` estimates the remaining cost to the nearest goal. The result is only
+/// guaranteed to be the cheapest if it never overestimates, e.g. the Manhattan distance on a map.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let (explored, goal) = astar_until(starts, successors, heuristic, is_goal);
+    goal.map(|goal| Found {
+        cost: explored.cost(&goal).unwrap(),
+        goal,
+        explored,
+    })
+}
+
+/// A state waiting in the queue, ordered so that the lowest estimated total cost comes first.
+struct Queued<S, C> {
+    estimate: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // ties prefer the state that is further along, which usually reaches the goal sooner.
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+fn astar_until<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> (Explored<S, C>, Option<S>)
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut explored = Explored::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = explored.nodes.entry(start.clone()) {
+            entry.insert((C::default(), None));
+            queue.push(Queued {
+                estimate: heuristic(&start),
+                cost: C::default(),
+                state: start,
+            });
+        }
+    }
+
+    while let Some(Queued { cost, state, .. }) = queue.pop() {
+        // a cheaper way to this state was found after it was queued.
+        if cost > explored.nodes[&state].0 {
+            continue;
+        }
+        if is_goal(&state) {
+            return (explored, Some(state));
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            match explored.nodes.entry(next.clone()) {
+                Entry::Occupied(entry) if entry.get().0 <= next_cost => continue,
+                Entry::Occupied(mut entry) => {
+                    entry.insert((next_cost, Some(state.clone())));
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert((next_cost, Some(state.clone())));
+                }
+            }
+            queue.push(Queued {
+                estimate: next_cost + heuristic(&next),
+                cost: next_cost,
+                state: next,
+            });
+        }
+    }
+
+    (explored, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::grid::Grid;
+    use rusttype::Point;
+
+    const MAZE: &str = "
+S.#.....
+.##.###.
+....#E..
+.####...";
+
+    fn maze() -> (Grid<char>, Point<i32>, Point<i32>) {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let start = grid.position(|&c| c == 'S').unwrap();
+        let end = grid.position(|&c| c == 'E').unwrap();
+        (grid, start, end)
+    }
+
+    #[test]
+    fn test_bfs() {
+        let (grid, start, end) = maze();
+        let open = |p: &Point<i32>| {
+            let p = *p;
+            grid.neighbours(p)
+                .filter(|&n| grid[n] != '#')
+                .collect::<Vec<_>>()
+        };
+
+        let found = bfs([start], open, |&p| p == end).unwrap();
+        assert_eq!(found.cost, 15);
+        let path = found.path();
+        assert_eq!((path[0], path[path.len() - 1]), (start, end));
+        assert_eq!(path.len(), 16);
+        assert!(path
+            .windows(2)
+            .all(|w| (w[0].x - w[1].x).abs() + (w[0].y - w[1].y).abs() == 1));
+
+        assert!(bfs([start], open, |&p| p == Point { x: 99, y: 0 }).is_none());
+
+        let explored = bfs_all([start], open);
+        assert_eq!(
+            explored.len(),
+            grid.iter().filter(|(_, &c)| c != '#').count()
+        );
+        assert_eq!(explored.cost(&end), Some(15));
+        assert_eq!(explored.predecessor(&start), None);
+        assert_eq!(explored.predecessors().len(), explored.len() - 1);
+    }
+
+    #[test]
+    fn test_bfs_multiple_starts() {
+        let found = bfs([0, 20], |&n: &i32| [n - 1, n + 1], |&n| n == 17).unwrap();
+        assert_eq!(found.cost, 3);
+        assert_eq!(found.path(), [20, 19, 18, 17]);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // the direct edge from a to d is more expensive than the detour through b and c.
+        let edges = HashMap::from([
+            ('a', vec![('b', 1), ('d', 10)]),
+            ('b', vec![('c', 2)]),
+            ('c', vec![('d', 3)]),
+            ('d', vec![]),
+            ('e', vec![('a', 1)]),
+        ]);
+        let successors = |n: &char| edges[n].clone();
+
+        let found = dijkstra(['a'], successors, |&n| n == 'd').unwrap();
+        assert_eq!(found.cost, 6);
+        assert_eq!(found.path(), ['a', 'b', 'c', 'd']);
+        assert!(dijkstra(['a'], successors, |&n| n == 'e').is_none());
+
+        let explored = dijkstra_all(['a'], successors);
+        assert_eq!(explored.cost(&'c'), Some(3));
+        assert_eq!(explored.path_to(&'d').unwrap(), ['a', 'b', 'c', 'd']);
+        assert!(!explored.contains(&'e'));
+    }
+
+    #[test]
+    fn test_astar() {
+        let (grid, start, end) = maze();
+        let successors = |&p: &Point<i32>| {
+            grid.neighbours(p)
+                .filter(|&n| grid[n] != '#')
+                .map(|n| (n, 1))
+                .collect::<Vec<_>>()
+        };
+        let manhattan = |p: &Point<i32>| (p.x - end.x).abs() + (p.y - end.y).abs();
+
+        let found = astar([start], successors, manhattan, |&p| p == end).unwrap();
+        let explored_by_dijkstra = dijkstra([start], successors, |&p| p == end).unwrap();
+        assert_eq!(found.cost, 15);
+        assert_eq!(found.cost, explored_by_dijkstra.cost);
+        assert!(found.explored.len() <= explored_by_dijkstra.explored.len());
+    }
+}
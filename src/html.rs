@@ -0,0 +1,488 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Just enough HTML handling for the markup of Advent of Code puzzle pages.
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Token {
+    /// Text with entities decoded.
+    Text(String),
+    Open {
+        name: String,
+        attributes: Vec<(String, String)>,
+    },
+    Close(String),
+}
+
+impl Token {
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        match self {
+            Token::Open { attributes, .. } => attributes
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value.as_str()),
+            _ => None,
+        }
+    }
+}
+
+/// Elements without a closing tag. A `Close` token is emitted right after their `Open` token.
+const VOID_ELEMENTS: &[&str] = &["br", "hr", "img", "input", "link", "meta"];
+
+/// Splits `html` into text and tags. Comments and doctypes are dropped. Tag names are lowercased.
+pub fn tokenize(html: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+        } else if rest.starts_with("<!") {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+        } else if let Some(tag) = rest.strip_prefix("</") {
+            let end = tag.find('>').unwrap_or(tag.len());
+            tokens.push(Token::Close(tag[..end].trim().to_lowercase()));
+            rest = tag.get(end + 1..).unwrap_or("");
+        } else if rest.starts_with('<') && rest[1..].starts_with(|c: char| c.is_ascii_alphabetic())
+        {
+            let end = tag_end(rest);
+            let (name, attributes) = parse_tag(rest[1..end].trim_end_matches('/'));
+            let void = rest[..end].ends_with('/') || VOID_ELEMENTS.contains(&name.as_str());
+            tokens.push(Token::Open {
+                name: name.clone(),
+                attributes,
+            });
+            if void {
+                tokens.push(Token::Close(name));
+            }
+            rest = rest.get(end + 1..).unwrap_or("");
+        } else {
+            // a `<` that does not start a tag is text.
+            let skip = usize::from(rest.starts_with('<'));
+            let end = rest[skip..].find('<').map_or(rest.len(), |i| i + skip);
+            tokens.push(Token::Text(decode_entities(&rest[..end])));
+            rest = &rest[end..];
+        }
+    }
+
+    tokens
+}
+
+/// Index of the `>` closing the tag at the start of `html`, skipping quoted attribute values.
+fn tag_end(html: &str) -> usize {
+    let mut quote = None;
+    for (i, c) in html.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '>') => return i,
+            _ => (),
+        }
+    }
+    html.len()
+}
+
+fn parse_tag(tag: &str) -> (String, Vec<(String, String)>) {
+    let name_end = tag.find(|c: char| c.is_whitespace()).unwrap_or(tag.len());
+    let name = tag[..name_end].to_lowercase();
+    let mut attributes = Vec::new();
+    let mut rest = tag[name_end..].trim_start();
+
+    while !rest.is_empty() {
+        let key_end = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let key = rest[..key_end].to_lowercase();
+        rest = rest[key_end..].trim_start();
+
+        let value = if let Some(value) = rest.strip_prefix('=') {
+            let value = value.trim_start();
+            let (raw, remaining) = match value.chars().next() {
+                Some(q @ ('"' | '\'')) => {
+                    let end = value[1..].find(q).map_or(value.len(), |i| i + 1);
+                    (&value[1..end], value.get(end + 1..).unwrap_or(""))
+                }
+                _ => {
+                    let end = value
+                        .find(|c: char| c.is_whitespace())
+                        .unwrap_or(value.len());
+                    (&value[..end], &value[end..])
+                }
+            };
+            rest = remaining.trim_start();
+            decode_entities(raw)
+        } else {
+            String::new()
+        };
+
+        if !key.is_empty() {
+            attributes.push((key, value));
+        }
+    }
+
+    (name, attributes)
+}
+
+pub fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        out += &rest[..start];
+        rest = &rest[start..];
+        let decoded = rest[1..]
+            .find(';')
+            .filter(|&end| end <= 10)
+            .and_then(|end| {
+                let entity = &rest[1..end + 1];
+                let c = match entity {
+                    "lt" => Some('<'),
+                    "gt" => Some('>'),
+                    "amp" => Some('&'),
+                    "quot" => Some('"'),
+                    "apos" => Some('\''),
+                    "nbsp" => Some('\u{a0}'),
+                    _ => entity
+                        .strip_prefix("#x")
+                        .or_else(|| entity.strip_prefix("#X"))
+                        .map(|hex| u32::from_str_radix(hex, 16))
+                        .or_else(|| entity.strip_prefix('#').map(|dec| dec.parse()))
+                        .and_then(|code| code.ok())
+                        .and_then(char::from_u32),
+                };
+                c.map(|c| (c, end + 2))
+            });
+
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out + rest
+}
+
+/// Returns the tokens inside each element `name` with the class `class`, e.g. every `<article class="day-desc">`.
+pub fn elements_with_class<'a>(tokens: &'a [Token], name: &str, class: &str) -> Vec<&'a [Token]> {
+    let mut elements = Vec::new();
+    let mut i = 0;
+
+    while i < tokens.len() {
+        let matches = matches!(&tokens[i], Token::Open { name: n, .. } if n == name)
+            && tokens[i]
+                .attribute("class")
+                .is_some_and(|classes| classes.split_whitespace().any(|c| c == class));
+        if !matches {
+            i += 1;
+            continue;
+        }
+
+        let start = i + 1;
+        let mut depth = 1;
+        let mut end = start;
+        while end < tokens.len() {
+            match &tokens[end] {
+                Token::Open { name: n, .. } if n == name => depth += 1,
+                Token::Close(n) if n == name => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                _ => (),
+            }
+            end += 1;
+        }
+        elements.push(&tokens[start..end]);
+        i = end + 1;
+    }
+
+    elements
+}
+
+/// Converts the markup used in puzzle descriptions to Markdown:
+/// headings, paragraphs, emphasis, inline code, code blocks, lists and links.
+/// Other tags are dropped, their text is kept. Relative links are resolved against `page_url`.
+pub fn to_markdown(tokens: &[Token], page_url: &str) -> String {
+    let mut md = Markdown {
+        out: String::new(),
+        lists: Vec::new(),
+        pre: false,
+        code: None,
+        links: Vec::new(),
+        page_url,
+    };
+
+    for token in tokens {
+        match token {
+            Token::Text(text) => md.text(text),
+            Token::Open { name, .. } => md.open(name, token),
+            Token::Close(name) => md.close(name),
+        }
+    }
+
+    let out = md.out.trim();
+    if out.is_empty() {
+        String::new()
+    } else {
+        format!("{}\n", out)
+    }
+}
+
+struct InlineCode {
+    text: String,
+    emphasized: bool,
+}
+
+struct Markdown<'a> {
+    out: String,
+    /// One entry per open list: `None` for `<ul>`, the next number for `<ol>`.
+    lists: Vec<Option<u32>>,
+    pre: bool,
+    code: Option<InlineCode>,
+    links: Vec<Option<String>>,
+    page_url: &'a str,
+}
+
+impl Markdown<'_> {
+    fn open(&mut self, name: &str, token: &Token) {
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.block();
+                let level = name[1..].parse().unwrap_or(2);
+                self.out += &"#".repeat(level);
+                self.out.push(' ');
+            }
+            "p" => self.block(),
+            "pre" => {
+                self.block();
+                self.out += "```\n";
+                self.pre = true;
+            }
+            "code" if !self.pre => {
+                self.code = Some(InlineCode {
+                    text: String::new(),
+                    emphasized: false,
+                })
+            }
+            "em" | "strong" | "b" | "i" => match &mut self.code {
+                Some(code) => code.emphasized = true,
+                None if !self.pre => self.out.push('*'),
+                None => (),
+            },
+            "ul" | "ol" => {
+                if self.lists.is_empty() {
+                    self.block();
+                }
+                self.lists.push((name == "ol").then_some(1));
+            }
+            "li" => {
+                self.line();
+                let depth = self.lists.len().saturating_sub(1);
+                self.out += &"  ".repeat(depth);
+                match self.lists.last_mut() {
+                    Some(Some(n)) => {
+                        self.out += &format!("{}. ", n);
+                        *n += 1;
+                    }
+                    _ => self.out += "- ",
+                }
+            }
+            "a" if self.code.is_none() && !self.pre => {
+                let href = token.attribute("href").map(|href| self.resolve(href));
+                if href.is_some() {
+                    self.out.push('[');
+                }
+                self.links.push(href);
+            }
+            "br" => self.out.push('\n'),
+            _ => (),
+        }
+    }
+
+    fn close(&mut self, name: &str) {
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "p" => self.block(),
+            "pre" => {
+                if !self.out.ends_with('\n') {
+                    self.out.push('\n');
+                }
+                self.out += "```";
+                self.pre = false;
+                self.block();
+            }
+            "code" if !self.pre => {
+                if let Some(code) = self.code.take() {
+                    let fence = if code.text.contains('`') { "``" } else { "`" };
+                    let padding = if code.text.contains('`') { " " } else { "" };
+                    let code_span = format!("{fence}{padding}{}{padding}{fence}", code.text);
+                    if code.emphasized {
+                        self.out += &format!("*{}*", code_span);
+                    } else {
+                        self.out += &code_span;
+                    }
+                }
+            }
+            "em" | "strong" | "b" | "i" if self.code.is_none() && !self.pre => self.out.push('*'),
+            "ul" | "ol" => {
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.block();
+                }
+            }
+            "a" => {
+                if let Some(Some(href)) = self.links.pop() {
+                    self.out += &format!("]({})", href);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.pre {
+            self.out += text;
+            return;
+        }
+        if let Some(code) = &mut self.code {
+            code.text += &text.replace('\n', " ");
+            return;
+        }
+
+        for c in text.chars() {
+            if c.is_whitespace() {
+                let at_line_start = self.out.is_empty() || self.out.ends_with([' ', '\n']);
+                if !at_line_start {
+                    self.out.push(' ');
+                }
+            } else {
+                if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<') {
+                    self.out.push('\\');
+                }
+                self.out.push(c);
+            }
+        }
+    }
+
+    /// Starts a new block, separated from the previous one by an empty line.
+    fn block(&mut self) {
+        let trimmed = self.out.trim_end().len();
+        self.out.truncate(trimmed);
+        if !self.out.is_empty() {
+            self.out += "\n\n";
+        }
+    }
+
+    /// Starts a new line, e.g. for a list item.
+    fn line(&mut self) {
+        let trimmed = self.out.trim_end_matches(' ').len();
+        self.out.truncate(trimmed);
+        if !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.out.push('\n');
+        }
+    }
+
+    fn resolve(&self, href: &str) -> String {
+        let page = self.page_url.split('#').next().unwrap_or_default();
+        if href.contains("://") || href.starts_with("mailto:") {
+            href.to_string()
+        } else if href.starts_with('/') {
+            let host_start = page.find("://").map_or(0, |i| i + 3);
+            let origin_end = page[host_start..]
+                .find('/')
+                .map_or(page.len(), |i| host_start + i);
+            format!("{}{}", &page[..origin_end], href)
+        } else if href.starts_with('#') {
+            format!("{}{}", page, href)
+        } else {
+            let dir_end = page.rfind('/').map_or(page.len(), |i| i + 1);
+            format!("{}{}", &page[..dir_end], href)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn markdown(html: &str) -> String {
+        to_markdown(&tokenize(html), "https://adventofcode.com/2022/day/1")
+    }
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(
+            tokenize("<!-- x --><p class='a b' title=\"1 > 0\">x &lt; y&#33;<br></P>"),
+            vec![
+                Token::Open {
+                    name: "p".into(),
+                    attributes: vec![
+                        ("class".into(), "a b".into()),
+                        ("title".into(), "1 > 0".into())
+                    ]
+                },
+                Token::Text("x < y!".into()),
+                Token::Open {
+                    name: "br".into(),
+                    attributes: vec![]
+                },
+                Token::Close("br".into()),
+                Token::Close("p".into()),
+            ]
+        );
+        assert_eq!(
+            tokenize("1 < 2 – ok"),
+            vec![Token::Text("1 ".into()), Token::Text("< 2 – ok".into())]
+        );
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("a &amp;&amp; b &#x3E; c &unknown; &"),
+            "a && b > c &unknown; &"
+        );
+    }
+
+    #[test]
+    fn test_elements_with_class() {
+        let tokens = tokenize(
+            "<article class=\"day-desc\"><p>one</p></article><article><p>no</p></article>\
+             <article class=\"x day-desc\"><p>two</p></article>",
+        );
+        let elements = elements_with_class(&tokens, "article", "day-desc");
+        assert_eq!(elements.len(), 2);
+        assert_eq!(elements[1][1], Token::Text("two".into()));
+    }
+
+    #[test]
+    fn test_to_markdown_inline() {
+        assert_eq!(
+            markdown("<p>The <em>Elves</em> take\n   turns: <code>a*b</code>, <code><em>24000</em></code> and <a href=\"/2022/day/1/input\">input</a>.</p>"),
+            "The *Elves* take turns: `a*b`, *`24000`* and [input](https://adventofcode.com/2022/day/1/input).\n"
+        );
+        assert_eq!(
+            markdown("<a href=\"1/input\">a</a> <a href=\"#part2\">b</a> <a href=\"https://x.org\">c</a>"),
+            "[a](https://adventofcode.com/2022/day/1/input) [b](https://adventofcode.com/2022/day/1#part2) [c](https://x.org)\n"
+        );
+        assert_eq!(markdown("<p>2 * 3 = [6]</p>"), "2 \\* 3 = \\[6\\]\n");
+    }
+
+    #[test]
+    fn test_to_markdown_blocks() {
+        assert_eq!(
+            markdown(
+                "<h2>--- Day 1 ---</h2><p>Example:</p>\n<pre><code>1000\n<em>2000</em>\n</code></pre>\n\
+                 <ul>\n<li>First <em>item</em>.</li>\n<li>Nested:<ul><li>inner</li></ul></li>\n</ul>\n<p>End.</p>"
+            ),
+            "## --- Day 1 ---\n\nExample:\n\n```\n1000\n2000\n```\n\n- First *item*.\n- Nested:\n  - inner\n\nEnd.\n"
+        );
+    }
+}
@@ -0,0 +1,246 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Overrides the directories `src/y<year>/inputs` that puzzle inputs are read from.
+/// Inputs are then read from `$AOC_INPUT_DIR/<year>/NN.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound {
+        path: PathBuf,
+    },
+    /// `puzzle` is the year and day of puzzle inputs, which can be fetched with `cargo download`.
+    Empty {
+        path: PathBuf,
+        puzzle: Option<(i16, u8)>,
+    },
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound { path } => {
+                write!(f, "could not find input file \"{}\"", path.display())
+            }
+            InputError::Empty { path, puzzle } => {
+                write!(f, "input file \"{}\" is empty", path.display())?;
+                if let Some((year, day)) = puzzle {
+                    write!(f, ", try `cargo download {} --year {}`", day, year)?;
+                }
+                Ok(())
+            }
+            InputError::Io { path, error } => {
+                write!(f, "could not read \"{}\": {}", path.display(), error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Where a puzzle input is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Parses the value of `--input`, where `-` stands for stdin.
+    pub fn from_arg(arg: &str) -> Result<Source, String> {
+        Ok(match arg {
+            "-" => Source::Stdin,
+            path => Source::File(PathBuf::from(path)),
+        })
+    }
+
+    pub fn read(&self, puzzle: Option<(i16, u8)>) -> Result<String, InputError> {
+        match self {
+            Source::File(path) => read_path(path, puzzle),
+            Source::Stdin => {
+                let path = PathBuf::from("<stdin>");
+                let mut contents = String::new();
+                match io::stdin().read_to_string(&mut contents) {
+                    Ok(_) => check_empty(contents, path, puzzle),
+                    Err(error) => Err(InputError::Io { path, error }),
+                }
+            }
+        }
+    }
+}
+
+/// The directory of a year's solutions and data, `src/y<year>` relative to the working directory.
+pub fn year_dir(year: i16) -> PathBuf {
+    let cwd = env::current_dir().unwrap();
+    cwd.join("src").join(format!("y{}", year))
+}
+
+/// `src/y<year>/<folder>/NN.txt` relative to the working directory.
+pub fn file_path(folder: &str, year: i16, day: u8) -> PathBuf {
+    year_dir(year).join(folder).join(format!("{:02}.txt", day))
+}
+
+/// The default location of a day's puzzle input, honoring `$AOC_INPUT_DIR`.
+pub fn input_path(year: i16, day: u8) -> PathBuf {
+    match env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir)
+            .join(year.to_string())
+            .join(format!("{:02}.txt", day)),
+        None => file_path("inputs", year, day),
+    }
+}
+
+/// Additional inputs of a day, e.g. those of other accounts, as `(name, path)` sorted by name.
+/// They live in a directory next to the default input: `inputs/NN/<name>.txt`.
+pub fn named_inputs(year: i16, day: u8) -> io::Result<Vec<(String, PathBuf)>> {
+    inputs_in(&input_path(year, day).with_extension(""))
+}
+
+/// The `.txt` files in `dir`. A missing directory contains no inputs.
+fn inputs_in(dir: &Path) -> io::Result<Vec<(String, PathBuf)>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(error),
+    };
+
+    let mut inputs = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "txt") {
+            let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+            inputs.push((name, path));
+        }
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+/// Reads a file, treating a file that contains nothing but whitespace as an error.
+pub fn read_path(path: &Path, puzzle: Option<(i16, u8)>) -> Result<String, InputError> {
+    match fs::read_to_string(path) {
+        Ok(contents) => check_empty(contents, path.to_path_buf(), puzzle),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Err(InputError::NotFound {
+            path: path.to_path_buf(),
+        }),
+        Err(error) => Err(InputError::Io {
+            path: path.to_path_buf(),
+            error,
+        }),
+    }
+}
+
+/// Writes `contents` to a temporary file next to `path` and renames it into place,
+/// so readers and concurrent writers never see a partially written file.
+pub fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let tmp_path = path.with_file_name(format!(
+        ".{}.{}.{}.tmp",
+        file_name,
+        process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    fs::write(&tmp_path, contents)?;
+    fs::rename(&tmp_path, path).inspect_err(|_| {
+        let _ = fs::remove_file(&tmp_path);
+    })
+}
+
+fn check_empty(
+    contents: String,
+    path: PathBuf,
+    puzzle: Option<(i16, u8)>,
+) -> Result<String, InputError> {
+    if contents.trim().is_empty() {
+        Err(InputError::Empty { path, puzzle })
+    } else {
+        Ok(contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_file(name: &str, contents: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("aoc_input_test_{}", name));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_read_path() {
+        let path = temp_file("ok", "1\n2\n");
+        assert_eq!(read_path(&path, Some((2022, 1))).unwrap(), "1\n2\n");
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_read_path_empty() {
+        let path = temp_file("empty", " \n");
+        let error = read_path(&path, Some((2021, 7))).unwrap_err();
+        assert!(matches!(error, InputError::Empty { .. }));
+        assert!(error.to_string().contains(&path.display().to_string()));
+        assert!(error
+            .to_string()
+            .ends_with("try `cargo download 7 --year 2021`"));
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_inputs_in() {
+        let dir = env::temp_dir().join("aoc_input_test_inputs_in");
+        fs::create_dir_all(&dir).unwrap();
+        for file in ["carol.txt", "alice.txt", "notes.md"] {
+            fs::write(dir.join(file), "1").unwrap();
+        }
+
+        let names: Vec<String> = inputs_in(&dir)
+            .unwrap()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, ["alice", "carol"]);
+        assert!(inputs_in(&dir.join("missing")).unwrap().is_empty());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_atomic() {
+        let dir = env::temp_dir().join("aoc_input_test_write_atomic");
+        let path = dir.join("01.txt");
+        write_atomic(&path, "old").unwrap();
+        write_atomic(&path, "new").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_read_path_not_found() {
+        let path = env::temp_dir().join("aoc_input_test_missing");
+        let error = read_path(&path, None).unwrap_err();
+        assert!(matches!(error, InputError::NotFound { .. }));
+        assert!(error.to_string().contains(&path.display().to_string()));
+    }
+}
@@ -1,125 +1,107 @@
 /*
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
- * Prefer `./helpers.rs` if you want to extract code from your solutions.
+ * Prefer `./helpers/mod.rs` if you want to extract code from your solutions.
  */
-use std::env;
-use std::fs;
-
+use cpu_time::ThreadTime;
+use std::fmt::Display;
+use std::process;
+use std::time::{Duration, Instant};
+
+pub mod answers;
+pub mod aoc;
+pub mod bitmap;
 pub mod helpers;
+pub mod html;
+pub mod input;
+pub mod puzzle;
+pub mod readme;
+pub mod registry;
+pub mod runner;
+pub mod submit;
+pub mod template;
+pub mod y2022;
+
+pub use input::file_path;
+
+// `cargo scaffold` declares new years above and adds them to this list.
+crate::years! {
+    2022 => y2022,
+}
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Wall-clock time and CPU time of the calling thread spent in a single solver call.
+/// The two differ noticeably when other solvers compete for the CPU, e.g. with `cargo all --jobs`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Timing {
+    pub wall: Duration,
+    pub cpu: Duration,
+}
+
+/// A solver with its answer type erased, as stored in the day registry.
+/// Returns the formatted answer together with the time the solver itself took.
+pub type Solver = fn(&str) -> (Option<String>, Timing);
+
 #[macro_export]
 macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
+    ($year:expr, $day:expr, $part:expr, $solver:ident, $input:expr) => {{
+        use advent_of_code::runner;
+
+        let options = runner::Options::from_env();
+        let answers = runner::load_answers($year, $day);
+        let solver: advent_of_code::Solver = |input| advent_of_code::run_timed($solver, input);
+        let result = runner::run_part(
+            $year,
+            $day,
+            $part,
+            solver,
+            $input,
+            answers.part($part),
+            &options,
+        );
+        runner::report(&result, &options);
 
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
+        // part two is the last part a day's binary runs.
+        if $part == 2 && runner::has_mismatch() {
+            std::process::exit(1);
         }
-
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
     }};
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
-
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+/// Times a single call of `func`. Formatting the answer is not part of the measured time.
+pub fn run_timed<T: Display>(
+    func: impl FnOnce(&str) -> Option<T>,
+    input: &str,
+) -> (Option<String>, Timing) {
+    let cpu_timer = ThreadTime::now();
+    let timer = Instant::now();
+    let result = func(input);
+    let wall = timer.elapsed();
+    let cpu = cpu_timer.elapsed();
+    (
+        result.map(|result| result.to_string()),
+        Timing { wall, cpu },
+    )
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// Reads `src/y<year>/<folder>/NN.txt`, panicking with the path it tried if the file is missing or empty.
+pub fn read_file(folder: &str, year: i16, day: u8) -> String {
+    let puzzle = (folder == "inputs").then_some((year, day));
+    input::read_path(&file_path(folder, year, day), puzzle).unwrap_or_else(|e| panic!("{}", e))
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
-        }
+/// Reads the puzzle input for a day from `--input` if given, otherwise from [`input::input_path`].
+/// Exits with an error message if it can't be read.
+pub fn read_input(year: i16, day: u8) -> String {
+    let options = runner::Options::from_env();
+    let source = options
+        .input
+        .unwrap_or_else(|| input::Source::File(input::input_path(year, day)));
+    source.read(Some((year, day))).unwrap_or_else(|e| {
+        eprintln!("Failed to read input: {}", e);
+        process::exit(1);
     })
 }
-
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
-    }
-}
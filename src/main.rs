@@ -2,45 +2,174 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::input::{self, InputError};
+use advent_of_code::runner::{self, Options, PartResult, Status};
+use advent_of_code::{readme, registry, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::collections::BTreeMap;
+use std::process;
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Runs both parts of a day.
+/// Returns why the day was not run if it has no solution or its input can't be read.
+fn run_day(day: u8, options: &Options) -> Result<Vec<PartResult>, String> {
+    let year = options.year;
+    let solution = registry::get(year, day).ok_or("Not solved.")?;
+    let input: Arc<str> = match input::read_path(&input::input_path(year, day), Some((year, day))) {
+        Ok(input) => input.into(),
+        Err(InputError::NotFound { .. }) => return Err("Not solved.".into()),
+        Err(e) => return Err(e.to_string()),
+    };
+    let answers = runner::load_answers(year, day);
+
+    let results = [solution.part_one, solution.part_two]
+        .into_iter()
+        .zip(1..)
+        .map(|(solver, part)| {
+            runner::run_part_isolated(
+                year,
+                day,
+                part,
+                solver,
+                input.clone(),
+                answers.part(part).map(String::from),
+                options,
+            )
+        })
+        .collect();
+
+    Ok(results)
+}
+
+fn print_day(day: u8, results: &Result<Vec<PartResult>, String>, options: &Options) {
+    println!("----------");
+    println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
+    println!("----------");
+
+    match results {
+        Ok(results) => results
+            .iter()
+            .for_each(|result| runner::report(result, options)),
+        Err(reason) => println!("{}", reason),
+    }
+}
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
-
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
-
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
-
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+    let options = Options::from_env();
+    runner::capture_panics();
+
+    if registry::year(options.year).is_none() {
+        eprintln!(
+            "There are no solutions for {}. Add one with `cargo scaffold <day> --year {}`.",
+            options.year, options.year
+        );
+        process::exit(1);
+    }
+
+    let started = Instant::now();
+    let next_day = AtomicU8::new(1);
+    let workers = AtomicUsize::new(options.jobs);
+    let (sender, receiver) = mpsc::channel();
+
+    // days are picked up by `options.jobs` workers, but printed in order as soon as all previous days are done.
+    let (total, total_cpu, finished_days) = thread::scope(|scope| {
+        for _ in 0..options.jobs {
+            let sender = sender.clone();
+            let (next_day, workers, options) = (&next_day, &workers, &options);
+            scope.spawn(move || loop {
+                let day = next_day.fetch_add(1, Ordering::Relaxed);
+                if day > 25 {
+                    break;
                 }
-            );
+                let results = run_day(day, options);
+                let timed_out = results
+                    .iter()
+                    .flatten()
+                    .any(|result| result.status == Status::Timeout);
+                sender.send((day, results)).unwrap();
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+                // a timed out part keeps using a CPU, so its worker leaves the CPU to it,
+                // unless it is the last worker and the remaining days would not run at all.
+                if timed_out {
+                    if workers.fetch_sub(1, Ordering::Relaxed) > 1 {
+                        break;
+                    }
+                    workers.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut next_to_print = 1;
+        let mut total = Duration::ZERO;
+        let mut total_cpu = Duration::ZERO;
+        let mut finished_days = Vec::new();
+
+        for (day, results) in receiver {
+            pending.insert(day, results);
+            while let Some(results) = pending.remove(&next_to_print) {
+                print_day(next_to_print, &results, &options);
+                // timed out and crashed parts have no meaningful timing.
+                for result in results.iter().flatten() {
+                    total += result.duration();
+                    total_cpu += result.cpu_duration();
+                }
+                if let Ok(results) = results {
+                    finished_days.push((next_to_print, results));
+                }
+                next_to_print += 1;
             }
-        })
-        .sum();
+        }
+
+        (total, total_cpu, finished_days)
+    });
+
+    if options.jobs > 1 {
+        println!(
+            "{}Total:{} {}{:.2}ms (cpu: {:.2}ms, wall-clock: {:.2}ms){}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            total.as_secs_f64() * 1000_f64,
+            total_cpu.as_secs_f64() * 1000_f64,
+            started.elapsed().as_secs_f64() * 1000_f64,
+            ANSI_RESET
+        );
+    } else {
+        println!(
+            "{}Total:{} {}{:.2}ms{}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            total.as_secs_f64() * 1000_f64,
+            ANSI_RESET
+        );
+    }
+
+    let disturbed = finished_days
+        .iter()
+        .flat_map(|(_, results)| results)
+        .any(|result| result.disturbed);
+    if disturbed {
+        println!(
+            "Timings marked as disturbed were measured while a timed out part was still running."
+        );
+    }
+
+    if options.readme {
+        match readme::update(options.year, &finished_days) {
+            Ok(()) => println!("Updated benchmarks in \"{}\".", readme::README_PATH),
+            Err(e) => {
+                eprintln!("Failed to update benchmarks in readme: {}", e);
+                process::exit(1);
+            }
+        }
+    }
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-    );
+    if runner::has_mismatch() {
+        process::exit(1);
+    }
 }
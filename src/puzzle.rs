@@ -0,0 +1,270 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::aoc::{Client, ClientError};
+use crate::html::{self, Token};
+use crate::input;
+use std::path::PathBuf;
+
+/// Every part of a puzzle description starts with a heading like `## --- Part Two ---`.
+const PART_HEADING: &str = "## --- ";
+
+pub fn path(year: i16, day: u8) -> PathBuf {
+    input::year_dir(year)
+        .join("puzzles")
+        .join(format!("{:02}.md", day))
+}
+
+pub fn url(client: &Client, year: i16, day: u8) -> String {
+    format!("{}/{}/day/{}", client.base_url(), year, day)
+}
+
+pub fn fetch(client: &Client, year: i16, day: u8) -> Result<String, ClientError> {
+    client.get(&format!("/{}/day/{}", year, day))
+}
+
+/// Converts every part of the puzzle on a page to Markdown.
+/// The page only shows part two once part one is solved.
+pub fn parts(page: &str, page_url: &str) -> Vec<String> {
+    let tokens = html::tokenize(page);
+    html::elements_with_class(&tokens, "article", "day-desc")
+        .into_iter()
+        .map(|article| html::to_markdown(article, page_url))
+        .collect()
+}
+
+/// How many parts of the puzzle a puzzle file contains.
+pub fn known_parts(contents: &str) -> usize {
+    contents
+        .lines()
+        .filter(|line| line.starts_with(PART_HEADING))
+        .count()
+}
+
+/// Returns the new contents of a puzzle file that currently contains `existing`,
+/// with the parts that are not in it yet appended. Returns `None` if there are no new parts.
+pub fn update(existing: Option<&str>, parts: &[String]) -> Option<String> {
+    let existing = existing.unwrap_or_default();
+    let known = known_parts(existing);
+    if known >= parts.len() {
+        return None;
+    }
+
+    let mut contents = existing.trim_end().to_string();
+    for part in &parts[known..] {
+        if !contents.is_empty() {
+            contents += "\n\n";
+        }
+        contents += part.trim_end();
+    }
+    contents.push('\n');
+    Some(contents)
+}
+
+/// The example of a puzzle and its answers, as far as they could be found on the puzzle page.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Example {
+    pub input: Option<String>,
+    /// The answers for the example, one per part on the page.
+    pub answers: Vec<Option<String>>,
+    /// Everything that was guessed and should be double-checked.
+    pub warnings: Vec<String>,
+}
+
+/// Finds the example input and the answers for it on a puzzle page.
+///
+/// The example input is the first code block in part one that follows a paragraph mentioning
+/// an "example". The answer for a part is the last emphasized code, e.g. `<code><em>24000</em></code>`.
+pub fn extract_example(page: &str) -> Example {
+    let tokens = html::tokenize(page);
+    let articles = html::elements_with_class(&tokens, "article", "day-desc");
+    let mut example = Example::default();
+
+    let blocks = articles
+        .first()
+        .map(|part| code_blocks(part))
+        .unwrap_or_default();
+    let mut candidates = blocks
+        .iter()
+        .filter(|(_, before)| before.to_lowercase().contains("example"));
+    example.input = match (candidates.next(), blocks.first()) {
+        (Some((block, _)), _) => {
+            if candidates.next().is_some() {
+                example.warnings.push(
+                    "found several code blocks introduced as an example, picked the first one"
+                        .into(),
+                );
+            }
+            Some(block.clone())
+        }
+        (None, Some((block, _))) => {
+            example
+                .warnings
+                .push("no code block is introduced as an example, picked the first one".into());
+            Some(block.clone())
+        }
+        (None, None) => {
+            example
+                .warnings
+                .push("found no code block for the example input".into());
+            None
+        }
+    };
+
+    for (i, part) in articles.iter().enumerate() {
+        let answer = emphasized_code(part).pop();
+        if answer.is_none() {
+            example
+                .warnings
+                .push(format!("found no answer for part {}", i + 1));
+        }
+        example.answers.push(answer);
+    }
+
+    example
+}
+
+/// The text of every `<pre>` block, with the text of the paragraph before it.
+fn code_blocks(tokens: &[Token]) -> Vec<(String, String)> {
+    let mut blocks = Vec::new();
+    let mut paragraph = String::new();
+    let mut block: Option<String> = None;
+
+    for token in tokens {
+        match (token, &mut block) {
+            (Token::Open { name, .. }, None) if name == "pre" => block = Some(String::new()),
+            (Token::Close(name), Some(text)) if name == "pre" => {
+                blocks.push((std::mem::take(text), std::mem::take(&mut paragraph)));
+                block = None;
+            }
+            (Token::Text(text), Some(block)) => *block += text,
+            (Token::Open { name, .. }, None) if name == "p" => paragraph.clear(),
+            (Token::Text(text), None) => paragraph += text,
+            _ => (),
+        }
+    }
+
+    blocks
+}
+
+/// The text of every emphasized code outside of code blocks, e.g. `<code><em>24000</em></code>`.
+fn emphasized_code(tokens: &[Token]) -> Vec<String> {
+    let mut values = Vec::new();
+    let (mut pre, mut code, mut em) = (0, 0, 0);
+    let mut value = String::new();
+
+    for token in tokens {
+        match token {
+            Token::Open { name, .. } => match name.as_str() {
+                "pre" => pre += 1,
+                "code" => code += 1,
+                "em" => em += 1,
+                _ => (),
+            },
+            Token::Close(name) => {
+                match name.as_str() {
+                    "pre" => pre -= 1,
+                    "code" => code -= 1,
+                    "em" => em -= 1,
+                    _ => (),
+                }
+                if (code == 0 || em == 0) && !value.is_empty() {
+                    values.push(std::mem::take(&mut value).trim().to_string());
+                }
+            }
+            Token::Text(text) if pre == 0 && code > 0 && em > 0 => value += text,
+            Token::Text(_) => (),
+        }
+    }
+
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE_URL: &str = "https://adventofcode.com/2022/day/1";
+
+    #[test]
+    fn test_parts() {
+        let parts = parts(include_str!("fixtures/part_one.html"), PAGE_URL);
+        assert_eq!(
+            parts,
+            vec!["## --- Day 1: Counting Pebbles ---
+
+The Elves are collecting *pebbles* for the expedition. Each Elf writes down the weight of every pebble they carry, one per line, with a blank line between Elves.
+
+For example:
+
+```
+3
+4
+
+10
+```
+
+This list represents pebbles carried by *two* Elves:
+
+- The first Elf carries pebbles weighing `3` and `4`, for a total of *`7`*.
+- The second Elf carries a single pebble weighing `10`.
+
+Find the Elf carrying the most weight. How much do they carry? In the example, that's *`10`*.
+"]
+        );
+    }
+
+    #[test]
+    fn test_update() {
+        let part_one = parts(include_str!("fixtures/part_one.html"), PAGE_URL);
+        let part_two = parts(include_str!("fixtures/part_two.html"), PAGE_URL);
+        assert_eq!(part_two.len(), 2);
+
+        let contents = update(None, &part_one).unwrap();
+        assert_eq!(contents, part_one[0]);
+        assert_eq!(known_parts(&contents), 1);
+        assert_eq!(update(Some(&contents), &part_one), None);
+
+        let contents = update(Some(&contents), &part_two).unwrap();
+        assert!(contents.starts_with(&part_one[0]));
+        assert!(contents.ends_with(
+            "## --- Part Two ---
+
+Now sum up the *two* heaviest loads & compare them to the [limit](https://adventofcode.com/2022/day/1#part2).
+
+In the example, the sum is *`17`*.
+"
+        ));
+        assert_eq!(update(Some(&contents), &part_two), None);
+    }
+
+    #[test]
+    fn test_extract_example() {
+        let example = extract_example(include_str!("fixtures/part_two.html"));
+        assert_eq!(
+            example,
+            Example {
+                input: Some("3\n4\n\n10\n".into()),
+                answers: vec![Some("10".into()), Some("17".into())],
+                warnings: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn test_extract_example_warnings() {
+        let example = extract_example(
+            "<article class=\"day-desc\"><p>Consider:</p><pre><code>1 <em>2</em></code></pre>\
+             <p>Then <em><code>3</code></em>.</p></article>",
+        );
+        assert_eq!(example.input.as_deref(), Some("1 2"));
+        assert_eq!(example.answers, vec![Some("3".into())]);
+        assert_eq!(example.warnings.len(), 1);
+
+        let example = extract_example("<article class=\"day-desc\"><p>No example.</p></article>");
+        assert_eq!(example.input, None);
+        assert_eq!(example.answers, vec![None]);
+        assert_eq!(example.warnings.len(), 2);
+    }
+}
@@ -0,0 +1,168 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::runner::{PartResult, Status};
+use std::fs;
+use std::time::Duration;
+
+pub const README_PATH: &str = "README.md";
+pub const TABLE_START: &str = "<!--- benchmarking table --->";
+pub const TABLE_END: &str = "<!--- benchmarking table end --->";
+
+fn format_duration(duration: Option<Duration>) -> String {
+    match duration {
+        Some(duration) => format!("`{:.2?}`", duration),
+        None => "—".into(),
+    }
+}
+
+fn solved_duration(result: Option<&PartResult>) -> Option<Duration> {
+    result
+        .filter(|result| result.status == Status::Solved)
+        .map(PartResult::duration)
+}
+
+/// Renders a markdown table with the timings of every day that was run.
+/// Parts without an answer are shown as "—". Days measured while a timed out part
+/// was still running are marked with a `*`, as their timings are unreliable.
+pub fn render_table(year: i16, days: &[(u8, Vec<PartResult>)]) -> String {
+    let mut table =
+        String::from("| Day | Part 1 | Part 2 | Total |\n| :---: | :---: | :---: | :---: |\n");
+    let mut total = Duration::ZERO;
+    let mut any_disturbed = false;
+
+    for (day, results) in days {
+        let part_one = solved_duration(results.iter().find(|r| r.part == 1));
+        let part_two = solved_duration(results.iter().find(|r| r.part == 2));
+        let day_total = match (part_one, part_two) {
+            (None, None) => None,
+            _ => Some(part_one.unwrap_or_default() + part_two.unwrap_or_default()),
+        };
+        total += day_total.unwrap_or_default();
+        let disturbed = results.iter().any(|result| result.disturbed);
+        any_disturbed |= disturbed;
+
+        table += &format!(
+            "| [Day {}](./src/y{}/day{:02}.rs){} | {} | {} | {} |\n",
+            day,
+            year,
+            day,
+            if disturbed { "\\*" } else { "" },
+            format_duration(part_one),
+            format_duration(part_two),
+            format_duration(day_total)
+        );
+    }
+
+    table += &format!("\n**Total: {:.2}ms**\n", total.as_secs_f64() * 1000_f64);
+    if any_disturbed {
+        table += "\n\\* measured while a timed out part was still running.\n";
+    }
+    table
+}
+
+/// Replaces everything between the table markers in `contents` with `table`.
+pub fn replace_table(contents: &str, table: &str) -> Result<String, String> {
+    let start = contents
+        .find(TABLE_START)
+        .ok_or_else(|| format!("could not find marker {}", TABLE_START))?
+        + TABLE_START.len();
+    let end = contents[start..]
+        .find(TABLE_END)
+        .ok_or_else(|| format!("could not find marker {}", TABLE_END))?
+        + start;
+
+    Ok(format!(
+        "{}\n\n{}\n{}",
+        &contents[..start],
+        table,
+        &contents[end..]
+    ))
+}
+
+pub fn update(year: i16, days: &[(u8, Vec<PartResult>)]) -> Result<(), String> {
+    let contents = fs::read_to_string(README_PATH).map_err(|e| e.to_string())?;
+    let contents = replace_table(&contents, &render_table(year, days))?;
+    fs::write(README_PATH, contents).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::Check;
+
+    fn result(part: u8, duration_ns: u128, status: Status) -> PartResult {
+        PartResult {
+            year: 2022,
+            day: 1,
+            part,
+            answer: None,
+            duration_ns,
+            cpu_ns: duration_ns,
+            status,
+            stats: None,
+            check: Check::Unknown,
+            disturbed: false,
+        }
+    }
+
+    #[test]
+    fn test_render_table() {
+        let days = vec![
+            (
+                1,
+                vec![
+                    result(1, 1_500_000, Status::Solved),
+                    result(2, 500_000, Status::Solved),
+                ],
+            ),
+            (
+                16,
+                vec![
+                    result(1, 100, Status::NotSolved),
+                    result(2, 100, Status::NotSolved),
+                ],
+            ),
+        ];
+
+        assert_eq!(
+            render_table(2022, &days),
+            "| Day | Part 1 | Part 2 | Total |\n\
+             | :---: | :---: | :---: | :---: |\n\
+             | [Day 1](./src/y2022/day01.rs) | `1.50ms` | `500.00µs` | `2.00ms` |\n\
+             | [Day 16](./src/y2022/day16.rs) | — | — | — |\n\
+             \n\
+             **Total: 2.00ms**\n"
+        );
+    }
+
+    #[test]
+    fn test_render_table_disturbed() {
+        let mut disturbed = result(1, 1_500_000, Status::Solved);
+        disturbed.disturbed = true;
+        let days = vec![(3, vec![disturbed, result(2, 500_000, Status::Timeout)])];
+
+        assert_eq!(
+            render_table(2022, &days),
+            "| Day | Part 1 | Part 2 | Total |\n\
+             | :---: | :---: | :---: | :---: |\n\
+             | [Day 3](./src/y2022/day03.rs)\\* | `1.50ms` | — | `1.50ms` |\n\
+             \n\
+             **Total: 1.50ms**\n\
+             \n\
+             \\* measured while a timed out part was still running.\n"
+        );
+    }
+
+    #[test]
+    fn test_replace_table() {
+        let contents = format!("# AoC\n{}\nold\n{}\nrest\n", TABLE_START, TABLE_END);
+        assert_eq!(
+            replace_table(&contents, "new\n").unwrap(),
+            format!("# AoC\n{}\n\nnew\n\n{}\nrest\n", TABLE_START, TABLE_END)
+        );
+
+        assert!(replace_table("# AoC\n", "new\n").is_err());
+    }
+}
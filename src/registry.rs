@@ -0,0 +1,57 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Solutions are registered per year so they can be run in-process by `cargo all`.
+//! `cargo scaffold` declares new years in `lib.rs` and adds them to the `years!` list there,
+//! and declares new days in the year's `mod.rs` and adds them to its `days!` list.
+//! The modules are declared as plain items so that `cargo fmt` sees them.
+use crate::Solver;
+
+pub struct Day {
+    pub day: u8,
+    pub part_one: Solver,
+    pub part_two: Solver,
+}
+
+pub struct Year {
+    pub year: i16,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub fn day(&self, day: u8) -> Option<&'static Day> {
+        self.days.iter().find(|d| d.day == day)
+    }
+}
+
+/// Collects the solutions of a year's day modules in `DAYS`.
+#[macro_export]
+macro_rules! days {
+    ($($day:literal => $module:ident,)*) => {
+        pub static DAYS: &[$crate::registry::Day] = &[$($crate::registry::Day {
+            day: $day,
+            part_one: |input| $crate::run_timed($module::part_one, input),
+            part_two: |input| $crate::run_timed($module::part_two, input),
+        },)*];
+    };
+}
+
+/// Collects the year modules in `YEARS`.
+#[macro_export]
+macro_rules! years {
+    ($($year:literal => $module:ident,)*) => {
+        pub static YEARS: &[$crate::registry::Year] = &[$($crate::registry::Year {
+            year: $year,
+            days: $module::DAYS,
+        },)*];
+    };
+}
+
+pub fn year(year: i16) -> Option<&'static Year> {
+    crate::YEARS.iter().find(|y| y.year == year)
+}
+
+pub fn get(year: i16, day: u8) -> Option<&'static Day> {
+    self::year(year)?.day(day)
+}
@@ -0,0 +1,1187 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::{self, Answers};
+use crate::aoc;
+use crate::input::{self, InputError, Source};
+use crate::registry;
+use crate::{Solver, Timing, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::cell::RefCell;
+use std::env;
+use std::fmt::Write;
+use std::panic;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Set as soon as a reported part does not match its known answer.
+static MISMATCH: AtomicBool = AtomicBool::new(false);
+
+/// The name of the threads `run_part_isolated` runs solvers on, see `capture_panics`.
+const SOLVER_THREAD: &str = "aoc-solver";
+
+/// The number of timed out solvers that are still running in the background.
+static RUNAWAY_SOLVERS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// The message of the last panic on this thread, stored by the hook installed in `capture_panics`.
+    static PANIC_MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Human,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Format::Human),
+            "json" => Ok(Format::Json),
+            _ => Err(format!(
+                "unknown format \"{}\", expected \"human\" or \"json\"",
+                s
+            )),
+        }
+    }
+}
+
+/// Settings for benchmark mode.
+/// Without a fixed number of `iterations`, parts are run for as long as `budget` allows.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bench {
+    pub warmup: u32,
+    pub iterations: Option<u32>,
+    pub budget: Duration,
+}
+
+impl Default for Bench {
+    fn default() -> Self {
+        Bench {
+            warmup: 3,
+            iterations: None,
+            budget: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Command line options shared by `cargo solve` and `cargo all`.
+#[derive(Clone, Debug)]
+pub struct Options {
+    pub format: Format,
+    pub bench: Option<Bench>,
+    /// Per-part time limit, only enforced by `cargo all`.
+    pub timeout: Option<Duration>,
+    /// Number of days `cargo all` runs concurrently.
+    pub jobs: usize,
+    /// Whether `cargo all` writes its timings into the readme.
+    pub readme: bool,
+    /// The year `cargo all` runs.
+    pub year: i16,
+    /// Whether `cargo solve` runs every input of the day, see [`run_all_inputs`].
+    pub all_inputs: bool,
+    /// The input `cargo solve` reads instead of the day's [`input::input_path`],
+    /// from `--input <path>` or `--input -` for stdin.
+    pub input: Option<Source>,
+}
+
+const USAGE: &str = "\
+Options:
+  --format <human|json>  how results are reported
+  --bench                run every part several times
+  --warmup <n>           runs before measuring, with --bench
+  --iterations <n>       measured runs, with --bench
+  --budget-ms <ms>       time to measure for, with --bench
+  --timeout-ms <ms>      time limit per part, for `cargo all`
+  -j, --jobs <n>         days to run concurrently, for `cargo all`
+  --readme               write the timings into the readme, for `cargo all`
+  -y, --year <year>      the year `cargo all` runs
+  --all-inputs           run `cargo solve` on every input of the day
+  --input <path|->       the input `cargo solve` reads";
+
+impl Options {
+    /// The options of this process. The arguments are only parsed on the first call,
+    /// exiting with a usage message if they are invalid.
+    pub fn from_env() -> Options {
+        static OPTIONS: OnceLock<Options> = OnceLock::new();
+        OPTIONS
+            .get_or_init(|| {
+                Options::parse(pico_args::Arguments::from_env()).unwrap_or_else(|e| {
+                    eprintln!("Failed to process arguments: {}\n\n{}", e, USAGE);
+                    std::process::exit(1);
+                })
+            })
+            .clone()
+    }
+
+    /// Parses all flags, rejecting any argument that is left over, e.g. a mistyped flag.
+    fn parse(mut args: pico_args::Arguments) -> Result<Options, String> {
+        let options = Options::parse_flags(&mut args).map_err(|e| e.to_string())?;
+
+        let remaining = args.finish();
+        if !remaining.is_empty() {
+            let remaining: Vec<_> = remaining.iter().map(|arg| arg.to_string_lossy()).collect();
+            return Err(format!("unexpected arguments: {}", remaining.join(" ")));
+        }
+        Ok(options)
+    }
+
+    fn parse_flags(args: &mut pico_args::Arguments) -> Result<Options, pico_args::Error> {
+        let format = args.opt_value_from_fn("--format", Format::from_str)?;
+
+        let bench_env = env::var("AOC_BENCH").is_ok_and(|v| !v.is_empty() && v != "0");
+        let bench = if args.contains("--bench") || bench_env {
+            let defaults = Bench::default();
+            Some(Bench {
+                warmup: args
+                    .opt_value_from_str("--warmup")?
+                    .unwrap_or(defaults.warmup),
+                iterations: args.opt_value_from_str("--iterations")?,
+                budget: args
+                    .opt_value_from_str("--budget-ms")?
+                    .map_or(defaults.budget, Duration::from_millis),
+            })
+        } else {
+            None
+        };
+
+        let timeout = args
+            .opt_value_from_str("--timeout-ms")?
+            .map(Duration::from_millis);
+
+        let jobs = args
+            .opt_value_from_str(["-j", "--jobs"])?
+            .map_or(1, |jobs: usize| jobs.max(1));
+
+        Ok(Options {
+            format: format.unwrap_or(Format::Human),
+            bench,
+            timeout,
+            jobs,
+            readme: args.contains("--readme"),
+            all_inputs: args.contains("--all-inputs"),
+            input: args.opt_value_from_fn("--input", Source::from_arg)?,
+            year: args
+                .opt_value_from_str(["-y", "--year"])?
+                .unwrap_or_else(aoc::default_year),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    NotSolved,
+    Timeout,
+    Crashed(String),
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::NotSolved => "not_solved",
+            Status::Timeout => "timeout",
+            Status::Crashed(_) => "crashed",
+        }
+    }
+
+    /// Whether the solver returned, i.e. whether its timing is meaningful.
+    pub fn is_finished(&self) -> bool {
+        matches!(self, Status::Solved | Status::NotSolved)
+    }
+}
+
+/// The result of comparing an answer with the known answer from `src/answers/`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Check {
+    Match,
+    Mismatch { expected: String },
+    Unknown,
+}
+
+/// Ignores trailing whitespace on every line and trailing blank lines,
+/// which are easy to get wrong when writing down a multi-line answer.
+fn normalize(answer: &str) -> String {
+    let lines: Vec<&str> = answer.lines().map(str::trim_end).collect();
+    lines.join("\n").trim_end_matches('\n').to_string()
+}
+
+impl Check {
+    /// A part without an answer is `Unknown` rather than a mismatch, so that days that are
+    /// not solved yet don't fail a run.
+    pub fn new(answer: Option<&str>, expected: Option<&str>) -> Check {
+        match (answer, expected) {
+            (None, _) | (_, None) => Check::Unknown,
+            (Some(answer), Some(expected)) if normalize(answer) == normalize(expected) => {
+                Check::Match
+            }
+            (Some(_), Some(expected)) => Check::Mismatch {
+                expected: expected.to_string(),
+            },
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Check::Match => "match",
+            Check::Mismatch { .. } => "mismatch",
+            Check::Unknown => "unknown",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Check::Match => "✅",
+            Check::Mismatch { .. } => "❌",
+            Check::Unknown => "❓",
+        }
+    }
+}
+
+/// Timing statistics over the measured runs of a benchmark.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: u32,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        let n = samples.len() as f64;
+        let mean = samples.iter().map(|d| d.as_secs_f64()).sum::<f64>() / n;
+        let variance = samples
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n;
+
+        Some(Stats {
+            runs: samples.len() as u32,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+
+    fn to_json(&self) -> String {
+        format!(
+            "{{\"runs\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{}}}",
+            self.runs,
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.mean.as_nanos(),
+            self.stddev.as_nanos()
+        )
+    }
+}
+
+/// The outcome of running one part of one day.
+/// When benchmarking, `duration_ns` holds the median of the measured runs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartResult {
+    pub year: i16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub duration_ns: u128,
+    pub cpu_ns: u128,
+    pub status: Status,
+    pub stats: Option<Stats>,
+    pub check: Check,
+    /// Whether a timed out solver was still running in the background while this part ran,
+    /// which makes its timing unreliable.
+    pub disturbed: bool,
+}
+
+impl PartResult {
+    /// A result for a part that did not finish.
+    fn unfinished(
+        year: i16,
+        day: u8,
+        part: u8,
+        status: Status,
+        expected: Option<&str>,
+    ) -> PartResult {
+        PartResult {
+            year,
+            day,
+            part,
+            answer: None,
+            duration_ns: 0,
+            cpu_ns: 0,
+            status,
+            stats: None,
+            check: Check::new(None, expected),
+            disturbed: false,
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_nanos(self.duration_ns as u64)
+    }
+
+    pub fn cpu_duration(&self) -> Duration {
+        Duration::from_nanos(self.cpu_ns as u64)
+    }
+
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\"duration_ns\":{},\"cpu_ns\":{},\"status\":\"{}\",\"stats\":{},\"check\":\"{}\",\"expected\":{},\"error\":{},\"disturbed\":{}}}",
+            self.year,
+            self.day,
+            self.part,
+            self.answer
+                .as_deref()
+                .map_or_else(|| "null".to_string(), json_string),
+            self.duration_ns,
+            self.cpu_ns,
+            self.status.as_str(),
+            self.stats
+                .as_ref()
+                .map_or_else(|| "null".to_string(), Stats::to_json),
+            self.check.as_str(),
+            match &self.check {
+                Check::Mismatch { expected } => json_string(expected),
+                _ => "null".to_string(),
+            },
+            match &self.status {
+                Status::Crashed(message) => json_string(message),
+                _ => "null".to_string(),
+            },
+            self.disturbed
+        )
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+pub fn run_part(
+    year: i16,
+    day: u8,
+    part: u8,
+    solver: Solver,
+    input: &str,
+    expected: Option<&str>,
+    options: &Options,
+) -> PartResult {
+    let mut result = run_once(year, day, part, solver, input, expected);
+    if let Some(bench) = &options.bench {
+        add_benchmark(&mut result, solver, input, bench);
+    }
+    result
+}
+
+/// Runs a part a single time.
+fn run_once(
+    year: i16,
+    day: u8,
+    part: u8,
+    solver: Solver,
+    input: &str,
+    expected: Option<&str>,
+) -> PartResult {
+    let (answer, timing) = solver(input);
+    let status = match answer {
+        Some(_) => Status::Solved,
+        None => Status::NotSolved,
+    };
+    let check = Check::new(answer.as_deref(), expected);
+
+    PartResult {
+        year,
+        day,
+        part,
+        answer,
+        duration_ns: timing.wall.as_nanos(),
+        cpu_ns: timing.cpu.as_nanos(),
+        status,
+        stats: None,
+        check,
+        disturbed: false,
+    }
+}
+
+/// Benchmarks a solved part and replaces the timing of its single run with the medians.
+fn add_benchmark(result: &mut PartResult, solver: Solver, input: &str, bench: &Bench) {
+    if result.status != Status::Solved {
+        return;
+    }
+
+    let samples = benchmark(solver, input, bench);
+    let wall = samples.iter().map(|t| t.wall).collect::<Vec<_>>();
+    let cpu = samples.iter().map(|t| t.cpu).collect::<Vec<_>>();
+    if let (Some(wall), Some(cpu)) = (Stats::from_samples(&wall), Stats::from_samples(&cpu)) {
+        result.duration_ns = wall.median.as_nanos();
+        result.cpu_ns = cpu.median.as_nanos();
+        result.stats = Some(wall);
+    }
+}
+
+/// Tracks whether the solver thread of `run_part_isolated` finished its first run
+/// or was abandoned because it timed out, so that it can leave [`RUNAWAY_SOLVERS`] when it finishes.
+#[derive(Default)]
+struct SolverState {
+    finished: bool,
+    abandoned: bool,
+}
+
+/// Runs a part on its own thread, so that a panicking solver is reported as crashed
+/// and one exceeding `options.timeout` as timed out instead of taking the runner down with it.
+/// A timed out solver can't be stopped and keeps running in the background until it returns.
+/// Meanwhile, the results of all other parts are marked as [`disturbed`](PartResult::disturbed).
+///
+/// The timeout only applies to the first run. A part that finishes in time is benchmarked
+/// afterwards, for as long as the benchmark takes.
+pub fn run_part_isolated(
+    year: i16,
+    day: u8,
+    part: u8,
+    solver: Solver,
+    input: Arc<str>,
+    expected: Option<String>,
+    options: &Options,
+) -> PartResult {
+    let disturbed = has_runaway_solvers();
+    let (sender, receiver) = mpsc::channel();
+    let state = Arc::new(Mutex::new(SolverState::default()));
+
+    {
+        let expected = expected.clone();
+        let bench = options.bench.clone();
+        let state = state.clone();
+        let spawned = thread::Builder::new()
+            .name(SOLVER_THREAD.into())
+            .spawn(move || {
+                let crashed = |payload: Box<dyn std::any::Any + Send>| {
+                    let message = PANIC_MESSAGE
+                        .with(|m| m.borrow_mut().take())
+                        .unwrap_or_else(|| panic_message(payload.as_ref()));
+                    PartResult::unfinished(
+                        year,
+                        day,
+                        part,
+                        Status::Crashed(message),
+                        expected.as_deref(),
+                    )
+                };
+
+                let result = panic::catch_unwind(|| {
+                    run_once(year, day, part, solver, &input, expected.as_deref())
+                })
+                .unwrap_or_else(crashed);
+
+                {
+                    let mut state = state.lock().unwrap();
+                    state.finished = true;
+                    if state.abandoned {
+                        RUNAWAY_SOLVERS.fetch_sub(1, Ordering::SeqCst);
+                        return;
+                    }
+                }
+                sender.send(result.clone()).ok();
+
+                if let Some(bench) = bench.filter(|_| result.status == Status::Solved) {
+                    let benchmarked = panic::catch_unwind(|| {
+                        let mut result = result.clone();
+                        add_benchmark(&mut result, solver, &input, &bench);
+                        result
+                    })
+                    .unwrap_or_else(crashed);
+                    sender.send(benchmarked).ok();
+                }
+            });
+        spawned.expect("failed to spawn solver thread");
+    }
+
+    let first = match options.timeout {
+        Some(timeout) => receiver.recv_timeout(timeout).ok().or_else(|| {
+            let mut state = state.lock().unwrap();
+            if state.finished {
+                // it finished just after the timeout, its result is on the way.
+                drop(state);
+                receiver.recv().ok()
+            } else {
+                state.abandoned = true;
+                RUNAWAY_SOLVERS.fetch_add(1, Ordering::SeqCst);
+                None
+            }
+        }),
+        None => receiver.recv().ok(),
+    };
+
+    let mut result = match first {
+        Some(first) if options.bench.is_some() && first.status == Status::Solved => {
+            receiver.recv().unwrap_or(first)
+        }
+        Some(first) => first,
+        None => PartResult::unfinished(year, day, part, Status::Timeout, expected.as_deref()),
+    };
+    result.disturbed = result.status.is_finished() && (disturbed || has_runaway_solvers());
+    result
+}
+
+/// Whether a timed out solver is still running in the background, competing for the CPU.
+pub fn has_runaway_solvers() -> bool {
+    RUNAWAY_SOLVERS.load(Ordering::SeqCst) > 0
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Keeps the message and location of panics on solver threads, instead of printing them,
+/// so that `run_part_isolated` can report them. Panics anywhere else, e.g. in the runner
+/// itself, are passed on to the previous hook.
+pub fn capture_panics() {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if thread::current().name() != Some(SOLVER_THREAD) {
+            return previous(info);
+        }
+
+        let mut message = panic_message(info.payload());
+        if let Some(location) = info.location() {
+            write!(message, " ({})", location).unwrap();
+        }
+        PANIC_MESSAGE.with(|m| *m.borrow_mut() = Some(message));
+    }));
+}
+
+/// Runs `solver` repeatedly and returns the timings of the measured runs.
+/// Every call gets its own copy of the input, so no run can profit from work done by a previous one.
+fn benchmark(solver: Solver, input: &str, bench: &Bench) -> Vec<Timing> {
+    let started = Instant::now();
+
+    for _ in 0..bench.warmup {
+        if started.elapsed() > bench.budget {
+            break;
+        }
+        let fresh_input = input.to_owned();
+        solver(&fresh_input);
+    }
+
+    let started = Instant::now();
+    let mut samples = Vec::new();
+
+    loop {
+        let done = match bench.iterations {
+            Some(iterations) => samples.len() >= iterations as usize,
+            None => !samples.is_empty() && started.elapsed() > bench.budget,
+        };
+        if done {
+            break;
+        }
+
+        let fresh_input = input.to_owned();
+        let (_, timing) = solver(&fresh_input);
+        samples.push(timing);
+    }
+
+    samples
+}
+
+/// Prints a result for humans on stdout.
+/// In json mode, the record is additionally written to stderr as a single line,
+/// keeping it apart from anything the solutions print themselves.
+pub fn report(result: &PartResult, options: &Options) {
+    // with concurrent days, wall-clock timings include time spent waiting for a CPU.
+    let mut cpu = if options.jobs > 1 {
+        format!(", cpu: {:.2?}", result.cpu_duration())
+    } else {
+        String::new()
+    };
+    if result.disturbed {
+        cpu += ", disturbed by a timed out part";
+    }
+
+    println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, result.part, ANSI_RESET);
+    print!("{} ", result.check.icon());
+    match &result.answer {
+        Some(answer) => {
+            let timing = match &result.stats {
+                Some(stats) => format!(
+                    "min: {:.2?}, median: {:.2?}, mean: {:.2?}, stddev: {:.2?}, runs: {}",
+                    stats.min, stats.median, stats.mean, stats.stddev, stats.runs
+                ),
+                None => format!("elapsed: {:.2?}", result.duration()),
+            };
+            let timing = format!("{}({}{}){}", ANSI_ITALIC, timing, cpu, ANSI_RESET);
+
+            // multi-line answers, e.g. a `Bitmap`, start on their own line so they stay aligned.
+            if answer.contains('\n') {
+                println!("{}\n{}", timing, answer);
+            } else {
+                println!("{} {}", answer, timing);
+            }
+        }
+        None => match &result.status {
+            Status::Timeout => println!("TIMEOUT"),
+            Status::Crashed(message) => println!("CRASHED: {}", message),
+            _ => println!("not solved."),
+        },
+    }
+
+    if let Check::Mismatch { expected } = &result.check {
+        MISMATCH.store(true, Ordering::Relaxed);
+        if expected.contains('\n') {
+            println!("   expected:\n{}", expected);
+        } else {
+            println!("   expected: {}", expected);
+        }
+    }
+
+    if options.format == Format::Json {
+        eprintln!("{}", result.to_json());
+    }
+}
+
+/// Whether any part reported so far did not match its known answer.
+pub fn has_mismatch() -> bool {
+    MISMATCH.load(Ordering::Relaxed)
+}
+
+/// The results of both parts on one of a day's inputs, or why the input could not be run.
+pub type InputResults = (String, Result<Vec<PartResult>, String>);
+
+/// Runs both parts of a registered day on its default input and on all of its
+/// [named inputs](input::named_inputs), prints a table and exits.
+/// Exits with a non-zero status code if any part crashed or does not match its known answer.
+pub fn run_all_inputs(year: i16, day: u8, options: &Options) -> ! {
+    let solution = registry::get(year, day).unwrap_or_else(|| {
+        eprintln!(
+            "Day {} of {} is not registered in \"src/y{}/mod.rs\".",
+            day, year, year
+        );
+        std::process::exit(1);
+    });
+    let named = input::named_inputs(year, day).unwrap_or_else(|e| {
+        eprintln!("Failed to list inputs: {}", e);
+        std::process::exit(1);
+    });
+
+    let default_path = input::input_path(year, day);
+    let mut inputs = vec![(
+        "default".to_string(),
+        default_path.clone(),
+        answers::path(year, day),
+    )];
+    inputs.extend(
+        named
+            .into_iter()
+            .map(|(name, path)| (name.clone(), path, answers::named_path(year, day, &name))),
+    );
+
+    capture_panics();
+    let mut rows: Vec<InputResults> = Vec::new();
+    for (name, input_path, answers_path) in inputs {
+        let input = match input::read_path(&input_path, None) {
+            Ok(input) => Arc::<str>::from(input),
+            // the default input is optional when there are other inputs.
+            Err(InputError::NotFound { .. }) if input_path == default_path => continue,
+            Err(e) => {
+                rows.push((name, Err(e.to_string())));
+                continue;
+            }
+        };
+        let answers = match answers::load_path(&answers_path) {
+            Ok(answers) => answers,
+            Err(e) => {
+                rows.push((name, Err(e)));
+                continue;
+            }
+        };
+
+        let results = [solution.part_one, solution.part_two]
+            .into_iter()
+            .zip(1..)
+            .map(|(solver, part)| {
+                let expected = answers.part(part).map(String::from);
+                run_part_isolated(year, day, part, solver, input.clone(), expected, options)
+            })
+            .collect();
+        rows.push((name, Ok(results)));
+    }
+
+    if rows.is_empty() {
+        eprintln!(
+            "No inputs found at \"{}\" or in \"{}\".",
+            default_path.display(),
+            default_path.with_extension("").display()
+        );
+        std::process::exit(1);
+    }
+
+    print!("{}", render_input_table(&rows));
+    let failed = rows.iter().any(|(_, results)| match results {
+        Ok(results) => results.iter().any(|result| {
+            matches!(result.check, Check::Mismatch { .. }) || !result.status.is_finished()
+        }),
+        Err(_) => true,
+    });
+    std::process::exit(i32::from(failed));
+}
+
+/// Renders one row per input with every part's answer, check and timing,
+/// followed by the expected answers of parts that don't match.
+pub fn render_input_table(rows: &[InputResults]) -> String {
+    let mut table = vec![vec![
+        "Input".to_string(),
+        "Part 1".to_string(),
+        "Part 2".to_string(),
+    ]];
+    let mut notes = Vec::new();
+
+    for (name, results) in rows {
+        let mut cells = vec![name.clone()];
+        match results {
+            Ok(results) => {
+                for result in results {
+                    cells.push(input_cell(result));
+                    if let Status::Crashed(message) = &result.status {
+                        notes.push(format!("{} part {}: {}", name, result.part, message));
+                    }
+                    if let Check::Mismatch { expected } = &result.check {
+                        notes.push(format!(
+                            "{} part {}: expected {}",
+                            name,
+                            result.part,
+                            one_line(expected)
+                        ));
+                    }
+                }
+            }
+            Err(reason) => {
+                cells.push("—".into());
+                cells.push("—".into());
+                notes.push(format!("{}: {}", name, reason));
+            }
+        }
+        table.push(cells);
+    }
+
+    let widths: Vec<usize> = (0..3)
+        .map(|column| {
+            table
+                .iter()
+                .map(|row| display_width(&row[column]))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let mut rendered = String::new();
+    for (i, row) in table.iter().enumerate() {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{}{}", cell, " ".repeat(width - display_width(cell))))
+            .collect();
+        writeln!(rendered, "{}", cells.join(" | ").trim_end()).unwrap();
+        if i == 0 {
+            let rules: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+            writeln!(rendered, "{}", rules.join("-+-")).unwrap();
+        }
+    }
+    for note in notes {
+        writeln!(rendered, "{}", note).unwrap();
+    }
+    rendered
+}
+
+fn input_cell(result: &PartResult) -> String {
+    let outcome = match (&result.answer, &result.status) {
+        (Some(answer), _) => format!("{} ({:.2?})", one_line(answer), result.duration()),
+        (None, Status::Timeout) => "TIMEOUT".into(),
+        (None, Status::Crashed(_)) => "CRASHED".into(),
+        (None, _) => "not solved".into(),
+    };
+    format!("{} {}", result.check.icon(), outcome)
+}
+
+/// The number of terminal columns `s` takes, counting the check icons as two.
+fn display_width(s: &str) -> usize {
+    s.chars()
+        .map(|c| {
+            if matches!(c, '✅' | '❌' | '❓') {
+                2
+            } else {
+                1
+            }
+        })
+        .sum()
+}
+
+/// Multi-line answers, e.g. a `Bitmap`, are joined with `/` to fit into a table cell.
+fn one_line(answer: &str) -> String {
+    answer.trim_end().lines().collect::<Vec<_>>().join("/")
+}
+
+/// Loads the known answers for a day, exiting if the answers file can't be read or is malformed.
+pub fn load_answers(year: i16, day: u8) -> Answers {
+    answers::load(year, day).unwrap_or_else(|e| {
+        eprintln!("Failed to read known answers: {}", e);
+        std::process::exit(1);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_options() -> Options {
+        Options {
+            format: Format::Human,
+            bench: None,
+            timeout: None,
+            jobs: 1,
+            readme: false,
+            year: 2022,
+            all_inputs: false,
+            input: None,
+        }
+    }
+
+    #[test]
+    fn test_to_json() {
+        let result = PartResult {
+            year: 2022,
+            day: 5,
+            part: 1,
+            answer: Some("C\"M\\Z\n".into()),
+            duration_ns: 1234,
+            cpu_ns: 1200,
+            status: Status::Solved,
+            stats: None,
+            check: Check::Match,
+            disturbed: false,
+        };
+        assert_eq!(
+            result.to_json(),
+            r#"{"year":2022,"day":5,"part":1,"answer":"C\"M\\Z\n","duration_ns":1234,"cpu_ns":1200,"status":"solved","stats":null,"check":"match","expected":null,"error":null,"disturbed":false}"#
+        );
+    }
+
+    #[test]
+    fn test_to_json_crashed() {
+        let result = PartResult {
+            year: 2022,
+            day: 16,
+            part: 2,
+            answer: None,
+            duration_ns: 0,
+            cpu_ns: 0,
+            status: Status::Crashed("oops".into()),
+            stats: None,
+            check: Check::Mismatch {
+                expected: "7".into(),
+            },
+            disturbed: false,
+        };
+        assert_eq!(
+            result.to_json(),
+            r#"{"year":2022,"day":16,"part":2,"answer":null,"duration_ns":0,"cpu_ns":0,"status":"crashed","stats":null,"check":"mismatch","expected":"7","error":"oops","disturbed":false}"#
+        );
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_micros);
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_nanos(2500));
+        assert_eq!(stats.mean, Duration::from_nanos(2500));
+        assert_eq!(stats.stddev.as_nanos(), 1118);
+
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_options_bench() {
+        let args = [
+            "--bench",
+            "--iterations",
+            "5",
+            "--format",
+            "json",
+            "-j",
+            "4",
+            "--year",
+            "2021",
+        ]
+        .map(std::ffi::OsString::from)
+        .to_vec();
+        let options = Options::parse(pico_args::Arguments::from_vec(args)).unwrap();
+        assert_eq!(options.format, Format::Json);
+        assert_eq!(options.jobs, 4);
+        assert_eq!(options.year, 2021);
+        assert_eq!(
+            options.bench,
+            Some(Bench {
+                iterations: Some(5),
+                ..Bench::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_options_rejects_unknown_arguments() {
+        let parse = |args: &[&str]| {
+            let args = args.iter().map(std::ffi::OsString::from).collect();
+            Options::parse(pico_args::Arguments::from_vec(args))
+        };
+
+        let options = parse(&["--input", "-", "--timeout-ms", "50"]).unwrap();
+        assert_eq!(options.input, Some(Source::Stdin));
+        assert_eq!(options.timeout, Some(Duration::from_millis(50)));
+
+        assert_eq!(
+            parse(&["--timout-ms", "50"]).unwrap_err(),
+            "unexpected arguments: --timout-ms 50"
+        );
+        assert!(parse(&["--jobs", "many"]).is_err());
+    }
+
+    #[test]
+    fn test_run_part() {
+        let solver: Solver = |input| crate::run_timed(|i: &str| Some(i.len()), input);
+        let options = test_options();
+        let result = run_part(2022, 1, 2, solver, "abc", Some("3"), &options);
+        assert_eq!(result.answer, Some("3".into()));
+        assert_eq!(result.status, Status::Solved);
+        assert_eq!((result.year, result.day, result.part), (2022, 1, 2));
+        assert_eq!(result.stats, None);
+        assert_eq!(result.check, Check::Match);
+
+        // an unfinished day with a partially filled answers file.
+        let unsolved: Solver = |input| crate::run_timed(|_: &str| -> Option<u32> { None }, input);
+        let result = run_part(2022, 1, 1, unsolved, "abc", Some("24000"), &options);
+        assert_eq!(result.status, Status::NotSolved);
+        assert_eq!(result.check, Check::Unknown);
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(Check::new(Some("1"), None), Check::Unknown);
+        assert_eq!(Check::new(Some("1"), Some("1")), Check::Match);
+        assert_eq!(
+            Check::new(Some("1"), Some("2")),
+            Check::Mismatch {
+                expected: "2".into()
+            }
+        );
+        // an unsolved part with a known answer is not a wrong answer.
+        assert_eq!(Check::new(None, Some("2")), Check::Unknown);
+        assert_eq!(Check::new(None, None), Check::Unknown);
+    }
+
+    #[test]
+    fn test_check_multi_line() {
+        assert_eq!(Check::new(Some("#.\n.#"), Some("#. \n.#\n")), Check::Match);
+        assert_eq!(
+            Check::new(Some("#.\n.#"), Some("#.\n##")),
+            Check::Mismatch {
+                expected: "#.\n##".into()
+            }
+        );
+    }
+
+    #[test]
+    fn test_run_part_bench() {
+        let solver: Solver = |input| crate::run_timed(|i: &str| Some(i.len()), input);
+        let options = Options {
+            bench: Some(Bench {
+                warmup: 1,
+                iterations: Some(10),
+                budget: Duration::from_secs(1),
+            }),
+            ..test_options()
+        };
+        let result = run_part(2022, 1, 1, solver, "abc", None, &options);
+        let stats = result.stats.unwrap();
+        assert_eq!(stats.runs, 10);
+        assert_eq!(result.duration_ns, stats.median.as_nanos());
+    }
+
+    #[test]
+    fn test_run_part_isolated() {
+        let options = Options {
+            timeout: Some(Duration::from_millis(50)),
+            ..test_options()
+        };
+
+        let ok: Solver = |input| crate::run_timed(|i: &str| Some(i.len()), input);
+        let result = run_part_isolated(2022, 1, 1, ok, "abc".into(), None, &options);
+        assert_eq!(result.status, Status::Solved);
+
+        let crash: Solver = |input| crate::run_timed(|_| -> Option<u32> { panic!("boom") }, input);
+        let result = run_part_isolated(2022, 1, 1, crash, "abc".into(), Some("3".into()), &options);
+        assert!(matches!(result.status, Status::Crashed(ref m) if m.starts_with("boom")));
+        assert_eq!(result.check, Check::Unknown);
+
+        let hang: Solver = |input| {
+            crate::run_timed(
+                |_| -> Option<u32> {
+                    thread::sleep(Duration::from_secs(1));
+                    None
+                },
+                input,
+            )
+        };
+        let result = run_part_isolated(2022, 1, 2, hang, "abc".into(), None, &options);
+        assert_eq!(result.status, Status::Timeout);
+        assert!(!result.status.is_finished());
+
+        // the hanging solver is still running and competes with the next part.
+        let result = run_part_isolated(2022, 2, 1, ok, "abc".into(), None, &options);
+        assert_eq!(result.status, Status::Solved);
+        assert!(result.disturbed);
+    }
+
+    #[test]
+    fn test_capture_panics() {
+        capture_panics();
+        let options = test_options();
+
+        let crash: Solver = |input| crate::run_timed(|_| -> Option<u32> { panic!("boom") }, input);
+        let result = run_part_isolated(2022, 1, 1, crash, "abc".into(), None, &options);
+        assert!(matches!(result.status, Status::Crashed(ref m) if m.contains("src/runner.rs")));
+
+        // panics outside of solver threads go to the previous hook instead.
+        let captured = thread::spawn(|| {
+            panic::catch_unwind(|| panic!("runner bug")).ok();
+            PANIC_MESSAGE.with(|m| m.borrow_mut().take())
+        });
+        assert_eq!(captured.join().unwrap(), None);
+    }
+
+    #[test]
+    fn test_run_part_isolated_bench() {
+        // the timeout is shorter than the benchmark, but only limits the first run.
+        let options = Options {
+            bench: Some(Bench {
+                warmup: 1,
+                iterations: None,
+                budget: Duration::from_millis(200),
+            }),
+            timeout: Some(Duration::from_millis(50)),
+            ..test_options()
+        };
+
+        let ok: Solver = |input| crate::run_timed(|i: &str| Some(i.len()), input);
+        let result = run_part_isolated(2022, 1, 1, ok, "abc".into(), Some("3".into()), &options);
+        assert_eq!(result.status, Status::Solved);
+        assert_eq!(result.check, Check::Match);
+        assert!(result.stats.unwrap().runs > 1);
+
+        let slow: Solver = |input| {
+            crate::run_timed(
+                |_| -> Option<u32> {
+                    thread::sleep(Duration::from_millis(200));
+                    Some(1)
+                },
+                input,
+            )
+        };
+        let result = run_part_isolated(2022, 1, 2, slow, "abc".into(), None, &options);
+        assert_eq!(result.status, Status::Timeout);
+    }
+
+    #[test]
+    fn test_render_input_table() {
+        let result =
+            |part: u8, answer: Option<&str>, status: Status, expected: Option<&str>| PartResult {
+                year: 2022,
+                day: 1,
+                part,
+                answer: answer.map(String::from),
+                duration_ns: 1_500_000,
+                cpu_ns: 1_500_000,
+                status,
+                stats: None,
+                check: Check::new(answer, expected),
+                disturbed: false,
+            };
+        let rows = vec![
+            (
+                "default".to_string(),
+                Ok(vec![
+                    result(1, Some("24000"), Status::Solved, Some("24000")),
+                    result(2, Some("45000"), Status::Solved, None),
+                ]),
+            ),
+            (
+                "alice".to_string(),
+                Ok(vec![
+                    result(1, Some("7"), Status::Solved, Some("8")),
+                    result(2, None, Status::Crashed("boom".into()), None),
+                ]),
+            ),
+            ("bob".to_string(), Err("input is empty".to_string())),
+        ];
+
+        assert_eq!(
+            render_input_table(&rows),
+            "Input   | Part 1            | Part 2\n\
+             --------+-------------------+------------------\n\
+             default | ✅ 24000 (1.50ms) | ❓ 45000 (1.50ms)\n\
+             alice   | ❌ 7 (1.50ms)     | ❓ CRASHED\n\
+             bob     | —                 | —\n\
+             alice part 1: expected 8\n\
+             alice part 2: boom\n\
+             bob: input is empty\n"
+        );
+    }
+
+    #[test]
+    fn test_one_line() {
+        assert_eq!(one_line("#..#\n####\n"), "#..#/####");
+    }
+}
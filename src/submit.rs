@@ -0,0 +1,449 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::aoc::{Client, ClientError};
+use std::env;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// How the website judged a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Another answer was submitted too recently.
+    Wait(Duration),
+    /// The part was already solved, so the answer was not judged.
+    AlreadySolved,
+    /// The response could not be understood. Contains the response's message.
+    Unknown(String),
+}
+
+impl Outcome {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::Wrong => "wrong",
+            Outcome::Wait(_) => "wait",
+            Outcome::AlreadySolved => "already_solved",
+            Outcome::Unknown(_) => "unknown",
+        }
+    }
+
+    /// Whether the answer itself was judged, which is what attempts are recorded for.
+    pub fn is_verdict(&self) -> bool {
+        matches!(
+            self,
+            Outcome::Correct | Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong
+        )
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "That's the right answer!"),
+            Outcome::TooHigh => write!(f, "That's not the right answer, it is too high."),
+            Outcome::TooLow => write!(f, "That's not the right answer, it is too low."),
+            Outcome::Wrong => write!(f, "That's not the right answer."),
+            Outcome::Wait(duration) => write!(
+                f,
+                "You gave an answer too recently, wait {}s before trying again.",
+                duration.as_secs()
+            ),
+            Outcome::AlreadySolved => write!(f, "This part is already solved."),
+            Outcome::Unknown(message) => write!(f, "Unexpected response: {}", message),
+        }
+    }
+}
+
+pub fn submit(
+    client: &Client,
+    year: i16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Outcome, ClientError> {
+    let level = part.to_string();
+    let response = client.post(
+        &format!("/{}/day/{}/answer", year, day),
+        &[("level", &level), ("answer", answer)],
+    )?;
+    Ok(parse_response(&response))
+}
+
+/// Reads the outcome from the message in the `<article>` of the response page.
+pub fn parse_response(html: &str) -> Outcome {
+    let message = article_text(html);
+
+    if message.contains("That's the right answer") {
+        Outcome::Correct
+    } else if message.contains("That's not the right answer") {
+        if message.contains("too high") {
+            Outcome::TooHigh
+        } else if message.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        }
+    } else if message.contains("You gave an answer too recently") {
+        Outcome::Wait(parse_wait(&message).unwrap_or(Duration::from_secs(60)))
+    } else if message.contains("Did you already complete it") {
+        Outcome::AlreadySolved
+    } else {
+        Outcome::Unknown(message)
+    }
+}
+
+/// The text of the page's `<article>` (or the whole page) without tags and with collapsed whitespace.
+fn article_text(html: &str) -> String {
+    let article = html
+        .find("<article")
+        .and_then(|start| {
+            html[start..]
+                .find("</article>")
+                .map(|end| &html[start..start + end])
+        })
+        .unwrap_or(html);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses "You have 1m 5s left to wait".
+fn parse_wait(message: &str) -> Option<Duration> {
+    let start = message.find("You have ")? + "You have ".len();
+    let end = message[start..].find(" left to wait")? + start;
+
+    let mut seconds = 0;
+    for token in message[start..end].split_whitespace() {
+        let (value, unit) = token.split_at(token.len() - 1);
+        let value: u64 = value.parse().ok()?;
+        seconds += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attempt {
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+/// Answers submitted for a day, stored in `.aoc/attempts/<year>-<day>.txt`
+/// as one tab-separated `part outcome answer` line per attempt.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Attempts {
+    pub attempts: Vec<Attempt>,
+}
+
+impl Attempts {
+    pub fn path(year: i16, day: u8) -> PathBuf {
+        let cwd = env::current_dir().unwrap();
+        cwd.join(".aoc")
+            .join("attempts")
+            .join(format!("{}-{:02}.txt", year, day))
+    }
+
+    /// Loads the attempts for a day. A missing file means that nothing was submitted yet.
+    pub fn load(year: i16, day: u8) -> Result<Attempts, String> {
+        Attempts::load_path(&Attempts::path(year, day))
+    }
+
+    /// Loads attempts from `path`. Any error other than a missing file is returned,
+    /// so that the checks against earlier attempts are never skipped silently.
+    pub fn load_path(path: &Path) -> Result<Attempts, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => {
+                Attempts::parse(&contents).map_err(|e| format!("{}: {}", path.display(), e))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Attempts::default()),
+            Err(e) => Err(format!("Failed to read \"{}\": {}", path.display(), e)),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Attempts, String> {
+        let attempts = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .map(|(i, line)| {
+                let mut fields = line.splitn(3, '\t');
+                let (part, outcome, answer) = match (fields.next(), fields.next(), fields.next()) {
+                    (Some(part), Some(outcome), Some(answer)) => (part, outcome, answer),
+                    _ => return Err(format!("line {}: expected `part outcome answer`", i + 1)),
+                };
+                let outcome = match outcome {
+                    "correct" => Outcome::Correct,
+                    "too_high" => Outcome::TooHigh,
+                    "too_low" => Outcome::TooLow,
+                    "wrong" => Outcome::Wrong,
+                    outcome => {
+                        return Err(format!("line {}: unknown outcome \"{}\"", i + 1, outcome))
+                    }
+                };
+                Ok(Attempt {
+                    part: part
+                        .parse()
+                        .map_err(|_| format!("line {}: invalid part \"{}\"", i + 1, part))?,
+                    answer: answer.to_string(),
+                    outcome,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Attempts { attempts })
+    }
+
+    /// Appends an attempt to the day's file. Outcomes that don't judge the answer are not recorded.
+    pub fn record(&mut self, year: i16, day: u8, attempt: Attempt) -> Result<(), String> {
+        if !attempt.outcome.is_verdict() {
+            return Ok(());
+        }
+
+        let path = Attempts::path(year, day);
+        let write = || {
+            fs::create_dir_all(path.parent().unwrap())?;
+            let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+            writeln!(
+                file,
+                "{}\t{}\t{}",
+                attempt.part,
+                attempt.outcome.as_str(),
+                attempt.answer
+            )
+        };
+        write().map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    /// A previous attempt with the same answer, if there is one.
+    pub fn find(&self, part: u8, answer: &str) -> Option<&Attempt> {
+        self.attempts
+            .iter()
+            .find(|attempt| attempt.part == part && attempt.answer == answer)
+    }
+
+    /// The largest answer known to be too low and the smallest answer known to be too high.
+    pub fn bounds(&self, part: u8) -> (Option<i64>, Option<i64>) {
+        let numbers = |outcome: Outcome| {
+            self.attempts
+                .iter()
+                .filter(move |attempt| attempt.part == part && attempt.outcome == outcome)
+                .filter_map(|attempt| attempt.answer.parse::<i64>().ok())
+        };
+        (
+            numbers(Outcome::TooLow).max(),
+            numbers(Outcome::TooHigh).min(),
+        )
+    }
+
+    /// Explains why a numeric answer must be wrong given the known bounds.
+    pub fn bound_violation(&self, part: u8, answer: &str) -> Option<String> {
+        let answer: i64 = answer.parse().ok()?;
+        match self.bounds(part) {
+            (Some(low), _) if answer <= low => Some(format!(
+                "{} is not above {}, which is known to be too low",
+                answer, low
+            )),
+            (_, Some(high)) if answer >= high => Some(format!(
+                "{} is not below {}, which is known to be too high",
+                answer, high
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// The time until which the website refuses new answers, stored in `.aoc/attempts/wait_until.txt`
+/// as seconds since the Unix epoch. It is shared by all days, as the limit applies to the account.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WaitUntil(pub SystemTime);
+
+impl WaitUntil {
+    pub fn path() -> PathBuf {
+        let cwd = env::current_dir().unwrap();
+        cwd.join(".aoc").join("attempts").join("wait_until.txt")
+    }
+
+    /// Loads the saved time from `path`. A missing file means that there is nothing to wait for.
+    pub fn load_path(path: &Path) -> Result<Option<WaitUntil>, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => contents
+                .trim()
+                .parse()
+                .map(|seconds| Some(WaitUntil(UNIX_EPOCH + Duration::from_secs(seconds))))
+                .map_err(|_| format!("{}: invalid timestamp {:?}", path.display(), contents)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(format!("Failed to read \"{}\": {}", path.display(), e)),
+        }
+    }
+
+    /// Saves the time to `path`, rounded up to whole seconds.
+    pub fn save_path(&self, path: &Path) -> Result<(), String> {
+        let since_epoch = self.0.duration_since(UNIX_EPOCH).unwrap_or_default();
+        let seconds = since_epoch.as_secs() + u64::from(since_epoch.subsec_nanos() > 0);
+        let write = || {
+            fs::create_dir_all(path.parent().unwrap())?;
+            fs::write(path, format!("{}\n", seconds))
+        };
+        write().map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// How long is left to wait at `now`, rounded up to whole seconds, if the time hasn't passed yet.
+    pub fn remaining(&self, now: SystemTime) -> Option<Duration> {
+        let left = self.0.duration_since(now).ok()?;
+        let seconds = left.as_secs() + u64::from(left.subsec_nanos() > 0);
+        (seconds > 0).then(|| Duration::from_secs(seconds))
+    }
+}
+
+/// Refuses to submit while the wait saved at `path` has not run out, so that
+/// the website is not asked again before it accepts answers.
+pub fn check_wait(path: &Path, now: SystemTime) -> Result<(), String> {
+    match WaitUntil::load_path(path)?.and_then(|until| until.remaining(now)) {
+        Some(remaining) => Err(Outcome::Wait(remaining).to_string()),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::tests::serve_once;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response(&page("That's the right answer! You are <span class=\"day-success\">one gold star</span> closer.")),
+            Outcome::Correct
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; please wait one minute before trying again. <a href=\"/2022/day/1\">[Return to Day 1]</a>")),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer.  If you're stuck, make sure you're using the full input data.")),
+            Outcome::Wrong
+        );
+        assert_eq!(
+            parse_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.")),
+            Outcome::Wait(Duration::from_secs(65))
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Outcome::AlreadySolved
+        );
+        assert_eq!(
+            parse_response(&page("Something <em>else</em>.")),
+            Outcome::Unknown("Something else.".into())
+        );
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, server) = serve_once(200, &page("That's the right answer!"));
+        let client = Client::new(&base_url, "abc");
+        assert_eq!(
+            submit(&client, 2022, 5, 2, "MCD").unwrap(),
+            Outcome::Correct
+        );
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2022/day/5/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("level=2&answer=MCD"));
+    }
+
+    #[test]
+    fn test_attempts() {
+        let attempts = Attempts::parse(
+            "1\ttoo_low\t10\n1\ttoo_high\t100\n1\ttoo_low\t20\n1\twrong\tabc\n2\ttoo_high\t5\n",
+        )
+        .unwrap();
+
+        assert_eq!(attempts.find(1, "abc").unwrap().outcome, Outcome::Wrong);
+        assert_eq!(attempts.find(2, "abc"), None);
+        assert_eq!(attempts.bounds(1), (Some(20), Some(100)));
+
+        assert_eq!(attempts.bound_violation(1, "50"), None);
+        assert!(attempts.bound_violation(1, "15").is_some());
+        assert!(attempts.bound_violation(1, "100").is_some());
+        assert_eq!(attempts.bound_violation(1, "xyz"), None);
+        assert!(attempts.bound_violation(2, "7").is_some());
+
+        assert!(Attempts::parse("1\tmaybe\t10").is_err());
+        assert!(Attempts::parse("1\t10").is_err());
+    }
+
+    #[test]
+    fn test_attempts_load_path() {
+        let missing = env::temp_dir().join("aoc_attempts_test_missing.txt");
+        assert_eq!(Attempts::load_path(&missing), Ok(Attempts::default()));
+
+        // a directory can't be read, which must not look like an empty history.
+        let dir = env::temp_dir().join("aoc_attempts_test_dir");
+        fs::create_dir_all(&dir).unwrap();
+        assert!(Attempts::load_path(&dir).is_err());
+        fs::remove_dir(&dir).ok();
+    }
+
+    #[test]
+    fn test_check_wait() {
+        let path = env::temp_dir().join("aoc_wait_until_test.txt");
+        fs::remove_file(&path).ok();
+        let now = UNIX_EPOCH + Duration::from_secs(1_000_000);
+        assert_eq!(check_wait(&path, now), Ok(()));
+
+        WaitUntil(now + Duration::from_millis(41_500))
+            .save_path(&path)
+            .unwrap();
+        assert_eq!(
+            check_wait(&path, now),
+            Err("You gave an answer too recently, wait 42s before trying again.".into())
+        );
+        assert_eq!(check_wait(&path, now + Duration::from_secs(42)), Ok(()));
+
+        fs::write(&path, "soon").unwrap();
+        assert!(check_wait(&path, now).is_err());
+        fs::remove_file(&path).ok();
+    }
+}
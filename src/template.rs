@@ -0,0 +1,148 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// Templates for new solutions, used by `cargo scaffold` instead of the built-in one:
+/// `.aoc/template.rs` by default, `.aoc/templates/<name>.rs` for `--template <name>`.
+pub fn path(name: Option<&str>) -> PathBuf {
+    let dir = env::current_dir().unwrap().join(".aoc");
+    match name {
+        Some(name) => dir.join("templates").join(format!("{}.rs", name)),
+        None => dir.join("template.rs"),
+    }
+}
+
+/// The names of the templates in `.aoc/templates`, sorted.
+pub fn names() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(path(Some("")).parent().unwrap())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let file_name = entry.ok()?.file_name().into_string().ok()?;
+            file_name.strip_suffix(".rs").map(String::from)
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+/// Loads a template. Returns `None` if no name is given and there is no default template,
+/// in which case the built-in template should be used. A named template has to exist.
+pub fn load(name: Option<&str>) -> Result<Option<String>, String> {
+    let path = path(name);
+    match (fs::read_to_string(&path), name) {
+        (Ok(template), _) => Ok(Some(template)),
+        (Err(_), None) if !path.exists() => Ok(None),
+        (Err(_), Some(name)) if !path.exists() => {
+            let names = names();
+            let available = if names.is_empty() {
+                "none".to_string()
+            } else {
+                names.join(", ")
+            };
+            Err(format!(
+                "template \"{}\" not found at \"{}\" (available: {})",
+                name,
+                path.display(),
+                available
+            ))
+        }
+        (Err(e), _) => Err(format!("{}: {}", path.display(), e)),
+    }
+}
+
+/// Replaces every `{{name}}` placeholder in `template` with its value.
+///
+/// Whitespace inside the braces is ignored. Braces that don't enclose a name, like `{{` in a
+/// format string, are kept as they are. A name without a value is an error, so that typos
+/// don't go unnoticed.
+pub fn render<V: AsRef<str>>(template: &str, values: &[(&str, V)]) -> Result<String, String> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        rendered += &rest[..start];
+        let inner = &rest[start + 2..];
+        let name = inner
+            .find("}}")
+            .map(|end| (inner[..end].trim(), end))
+            .filter(|(name, _)| is_name(name));
+
+        match name {
+            Some((name, end)) => {
+                let value = values
+                    .iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value)
+                    .ok_or_else(|| {
+                        let offset = template.len() - rest.len() + start;
+                        format!(
+                            "unknown placeholder `{{{{{}}}}}` on line {}",
+                            name,
+                            template[..offset].matches('\n').count() + 1
+                        )
+                    })?;
+                rendered += value.as_ref();
+                rest = &inner[end + 2..];
+            }
+            None => {
+                rendered += "{{";
+                rest = inner;
+            }
+        }
+    }
+
+    rendered += rest;
+    Ok(rendered)
+}
+
+fn is_name(s: &str) -> bool {
+    !s.is_empty()
+        && s.chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALUES: &[(&str, &str)] = &[("day", "5"), ("day_padded", "05"), ("year", "2022")];
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render("use advent_of_code::y{{year}}::day{{day_padded}};", VALUES),
+            Ok("use advent_of_code::y2022::day05;".to_string())
+        );
+        assert_eq!(
+            render("read_file(\"examples\", {{ year }}, {{day}})", VALUES),
+            Ok("read_file(\"examples\", 2022, 5)".to_string())
+        );
+        // only placeholders are replaced, not other occurrences of their names.
+        assert_eq!(
+            render("const DAY: u8 = {{day}}; // day", VALUES),
+            Ok("const DAY: u8 = 5; // day".to_string())
+        );
+    }
+
+    #[test]
+    fn test_render_keeps_other_braces() {
+        let template = "format!(\"{{}} {{x:?}} {{{{\", a) {{ day }} {{ Day }} {{";
+        assert_eq!(
+            render(template, VALUES),
+            Ok("format!(\"{{}} {{x:?}} {{{{\", a) 5 {{ Day }} {{".to_string())
+        );
+    }
+
+    #[test]
+    fn test_render_unknown_placeholder() {
+        assert_eq!(
+            render("fn main() {\n    {{dya}}\n}", VALUES),
+            Err("unknown placeholder `{{dya}}` on line 2".to_string())
+        );
+    }
+}
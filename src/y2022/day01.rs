@@ -1,11 +1,16 @@
+use crate::helpers::parse::parse_lines;
 use itertools::sorted;
 
 pub fn get_sum(input: &str, item_count: usize) -> u32 {
-    let all = input.trim().split("\n\n").into_iter().map(|elf| {
-        elf.split_whitespace()
-            .map(|x| x.parse::<u32>().unwrap())
-            .sum::<u32>()
-    });
+    // blank lines separate the elves.
+    let calories = parse_lines(input, |line| match line.trim() {
+        "" => Ok(None),
+        line => line.parse::<u32>().map(Some),
+    })
+    .unwrap();
+    let all = calories
+        .split(Option::is_none)
+        .map(|elf| elf.iter().flatten().sum::<u32>());
 
     sorted(all).rev().take(item_count).sum()
 }
@@ -18,25 +23,19 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(get_sum(input, 3))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 1);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 1);
+        let input = crate::read_file("examples", 2022, 1);
         assert_eq!(part_one(&input), Some(24000));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 1);
+        let input = crate::read_file("examples", 2022, 1);
         assert_eq!(part_two(&input), Some(45000));
     }
 }
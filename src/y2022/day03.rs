@@ -39,25 +39,19 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(sum)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 3);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 3);
+        let input = crate::read_file("examples", 2022, 3);
         assert_eq!(part_one(&input), Some(157));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 3);
+        let input = crate::read_file("examples", 2022, 3);
         assert_eq!(part_two(&input), Some(70));
     }
 }
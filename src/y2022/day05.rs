@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use crate::helpers::parse::{extract, paragraphs, parse_lines};
 use itertools::Itertools;
 
 fn get_stacks(stacks_str: &str) -> HashMap<u32, Vec<char>> {
@@ -15,22 +16,14 @@ fn get_stacks(stacks_str: &str) -> HashMap<u32, Vec<char>> {
 }
 
 pub fn get_instructions(instructions_str: &str) -> Vec<(u32, u32, u32)> {
-    instructions_str
-        .trim()
-        .lines()
-        .map(|line| {
-            let (_, count, _, from, _, to) = line.split_whitespace().collect_tuple().unwrap();
-            (
-                count.parse().unwrap(),
-                from.parse().unwrap(),
-                to.parse().unwrap(),
-            )
-        })
-        .collect_vec()
+    parse_lines(instructions_str, |line| {
+        extract("move {} from {} to {}", line)
+    })
+    .unwrap()
 }
 
 pub fn part_one(input: &str) -> Option<String> {
-    let (stacks_str, instructions_str) = input.split("\n\n").collect_tuple().unwrap();
+    let (stacks_str, instructions_str) = paragraphs(input).collect_tuple().unwrap();
     let mut stacks = get_stacks(stacks_str);
     for (count, from, to) in get_instructions(instructions_str) {
         for _i in 0..count {
@@ -46,7 +39,7 @@ pub fn part_one(input: &str) -> Option<String> {
 }
 
 pub fn part_two(input: &str) -> Option<String> {
-    let (stacks_str, instructions_str) = input.split("\n\n").collect_tuple().unwrap();
+    let (stacks_str, instructions_str) = paragraphs(input).collect_tuple().unwrap();
     let mut stacks = get_stacks(stacks_str);
     for (count, from, to) in get_instructions(instructions_str) {
         let stack = stacks.get_mut(&from).unwrap();
@@ -60,25 +53,19 @@ pub fn part_two(input: &str) -> Option<String> {
     )
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 5);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 5);
+        let input = crate::read_file("examples", 2022, 5);
         assert_eq!(part_one(&input), Some(String::from("CMZ")));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 5);
+        let input = crate::read_file("examples", 2022, 5);
         assert_eq!(part_two(&input), Some(String::from("MCD")));
     }
 }
@@ -54,8 +54,8 @@ pub fn part_one(input: &str) -> Option<usize> {
     let fs = get_fs(input);
 
     let size: usize = fs
-        .iter()
-        .map(|(k, _)| get_size(&fs, k))
+        .keys()
+        .map(|k| get_size(&fs, k))
         .filter(|v| *v < 100000)
         .sum();
 
@@ -68,33 +68,27 @@ pub fn part_two(input: &str) -> Option<usize> {
     let free = 70000000 - get_size(&fs, &PathBuf::from_str("/").unwrap());
 
     let larger = fs
-        .iter()
-        .map(|(k, _)| get_size(&fs, k))
+        .keys()
+        .map(|k| get_size(&fs, k))
         .filter(|v| *v > 30000000 - free)
         .fold(usize::MAX, |acc, v| acc.min(v));
 
     Some(larger)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = crate::read_file("examples", 2022, 7);
         assert_eq!(part_one(&input), Some(95437));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = crate::read_file("examples", 2022, 7);
         assert_eq!(part_two(&input), Some(24933642));
     }
 }
@@ -0,0 +1,67 @@
+use crate::helpers::grid::Grid;
+use crate::helpers::DIRECTIONS;
+use rusttype::{Point, Vector};
+
+fn parse_trees(input: &str) -> Grid<u32> {
+    Grid::parse(input.trim(), |c| c.to_digit(10).unwrap()).unwrap()
+}
+
+/// How many trees can be seen from `tree` in `direction`, including the tree that blocks the view.
+fn viewing_distance(trees: &Grid<u32>, tree: Point<i32>, direction: Vector<i32>) -> u32 {
+    let height = trees[tree];
+    let mut distance = 0;
+    for (_, &other) in trees.ray(tree, direction) {
+        distance += 1;
+        if other >= height {
+            break;
+        }
+    }
+    distance
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let trees = parse_trees(input);
+
+    // trees on the edge have nothing in front of them, so they are always visible.
+    let visible = trees
+        .iter()
+        .filter(|&(tree, &height)| {
+            DIRECTIONS
+                .iter()
+                .any(|&direction| trees.ray(tree, direction).all(|(_, &other)| other < height))
+        })
+        .count();
+
+    Some(visible as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let trees = parse_trees(input);
+
+    trees
+        .points()
+        .map(|tree| {
+            DIRECTIONS
+                .iter()
+                .map(|&direction| viewing_distance(&trees, tree, direction))
+                .product()
+        })
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 8);
+        assert_eq!(part_one(&input), Some(21));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 8);
+        assert_eq!(part_two(&input), Some(8));
+    }
+}
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
-use itertools::Itertools;
+use crate::helpers::direction::Direction;
+use crate::helpers::parse::{extract, parse_lines};
 use rusttype::{Point, Vector};
 
 fn limit_vector(mut mov_dir: Vector<i32>) -> Option<Vector<i32>> {
@@ -20,18 +21,13 @@ fn limit_vector(mut mov_dir: Vector<i32>) -> Option<Vector<i32>> {
     None
 }
 
-fn do_rope_physics(
-    instructions: Vec<(char, i32)>,
-    directions: HashMap<char, Vector<i32>>,
-    rope_lenght: usize,
-) -> usize {
+fn do_rope_physics(instructions: Vec<(Direction, i32)>, rope_length: usize) -> usize {
     let mut head = Point { x: 0, y: 0 };
-    let mut rope: Vec<Point<i32>> = vec![Point { x: 0, y: 0 }; rope_lenght];
+    let mut rope: Vec<Point<i32>> = vec![Point { x: 0, y: 0 }; rope_length];
     let mut visited: HashMap<Point<i32>, bool> = HashMap::new();
     for (d, v) in instructions {
-        let dir = directions.get(&d).unwrap();
         for _ in 0..v {
-            head = head + *dir;
+            head = head + d.vector();
             let mut previous = head;
             for knot in rope.iter_mut() {
                 let mov_dir = previous - *knot;
@@ -46,59 +42,34 @@ fn do_rope_physics(
     visited.len()
 }
 
-fn parse_instructions(input: &str) -> Vec<(char, i32)> {
-    let instructions = input
-        .trim()
-        .lines()
-        .map(|line| {
-            let (d, v) = line.split_whitespace().collect_tuple().unwrap();
-            (d.chars().next().unwrap(), v.parse::<i32>().unwrap())
-        })
-        .collect_vec();
-    instructions
-}
-fn get_directions() -> HashMap<char, Vector<i32>> {
-    let directions: HashMap<char, Vector<i32>> = HashMap::from_iter([
-        ('L', Vector { x: -1, y: 0 }),
-        ('R', Vector { x: 1, y: 0 }),
-        ('U', Vector { x: 0, y: -1 }),
-        ('D', Vector { x: 0, y: 1 }),
-    ]);
-    directions
+fn parse_instructions(input: &str) -> Vec<(Direction, i32)> {
+    parse_lines(input, |line| extract("{} {}", line)).unwrap()
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
-    let directions = get_directions();
     let instructions = parse_instructions(input);
-    let sum = do_rope_physics(instructions, directions, 1);
+    let sum = do_rope_physics(instructions, 1);
     Some(sum as u32)
 }
 pub fn part_two(input: &str) -> Option<u32> {
-    let directions = get_directions();
     let instructions = parse_instructions(input);
-    let sum = do_rope_physics(instructions, directions, 9);
+    let sum = do_rope_physics(instructions, 9);
     Some(sum as u32)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 9);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 9);
+        let input = crate::read_file("examples", 2022, 9);
         assert_eq!(part_one(&input), Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 9);
+        let input = crate::read_file("examples", 2022, 9);
         assert_eq!(part_two(&input), Some(1));
     }
 }
@@ -1,3 +1,5 @@
+use crate::bitmap::Bitmap;
+use crate::helpers::ocr;
 use std::collections::VecDeque;
 
 #[derive(Clone)]
@@ -52,7 +54,8 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(signal as u32)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+fn draw(input: &str) -> Bitmap {
+    let mut screen = Bitmap::new(40, 6);
     let mut instructions = parse_instructions(input.trim());
     let mut state: i32 = 1;
     let mut execution_slot: (u32, Instruction) = (0, Instruction::Nop);
@@ -74,23 +77,18 @@ pub fn part_two(input: &str) -> Option<u32> {
         } else {
             execution_slot.0 -= 1;
         }
-        if c % 40 == 0 {
-            println!();
+        if c < 240 && ((c % 40) - state).abs() <= 1 {
+            screen.set((c % 40) as usize, (c / 40) as usize, true);
         }
-        let screen = if ((c % 40) - state).abs() > 1 {
-            '.'
-        } else {
-            '#'
-        };
-        print!("{screen}");
     }
-    None
+    screen
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub fn part_two(input: &str) -> Option<String> {
+    let screen = draw(input);
+    let text = ocr::recognize(&screen)
+        .unwrap_or_else(|e| panic!("could not read screen: {}\n\n{}", e, screen));
+    Some(text)
 }
 
 #[cfg(test)]
@@ -99,13 +97,28 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10);
+        let input = crate::read_file("examples", 2022, 10);
         assert_eq!(part_one(&input), Some(13140));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_two(&input), None);
+        let input = crate::read_file("examples", 2022, 10);
+        let expected = "
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+        assert_eq!(draw(&input), expected.parse().unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "could not read screen")]
+    fn test_part_two_unreadable() {
+        // the example screen does not show letters.
+        let input = crate::read_file("examples", 2022, 10);
+        part_two(&input);
     }
 }
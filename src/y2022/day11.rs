@@ -1,5 +1,6 @@
+use crate::helpers::parse::{ints, paragraphs};
 use itertools::Itertools;
-use std::{collections::VecDeque, str::FromStr};
+use std::{collections::VecDeque, fmt, str::FromStr};
 
 #[derive(Clone, Copy)]
 enum Operation {
@@ -33,21 +34,18 @@ struct Monkey {
     item_counter: u64,
 }
 
-fn get_last_val<T: FromStr>(str_: &str) -> Option<T> {
-    let (_, divisor_str) = str_.split_once(':').unwrap();
-    match divisor_str.split_whitespace().last().unwrap().parse::<T>() {
-        Ok(v) => Some(v),
-        _ => None,
-    }
+fn get_last_val<T: FromStr>(str_: &str) -> Option<T>
+where
+    T::Err: fmt::Display,
+{
+    ints(str_).last().map(Result::unwrap)
 }
 
 fn parse_monkey(input: &str) -> Monkey {
     let mut itr = input.lines().skip(1);
-    let (_, item_str) = itr.next().unwrap().split_once(':').unwrap();
-    let items = item_str
-        .split(',')
-        .map(|v| v.trim().parse::<u64>().unwrap())
-        .collect::<VecDeque<u64>>();
+    let items = ints(itr.next().unwrap())
+        .collect::<Result<VecDeque<u64>, _>>()
+        .unwrap();
 
     let (_, operation_str) = itr.next().unwrap().split_once(':').unwrap();
     let (_, expression) = operation_str.split_once('=').unwrap();
@@ -118,36 +116,30 @@ fn monkey_stuff(mut monkeys: Vec<Monkey>, rounds: u32, do_div: bool) -> u64 {
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let monkeys = input.trim().split("\n\n").map(parse_monkey).collect_vec();
+    let monkeys = paragraphs(input).map(parse_monkey).collect_vec();
     let s = monkey_stuff(monkeys, 20, true);
     Some(s)
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let monkeys = input.trim().split("\n\n").map(parse_monkey).collect_vec();
+    let monkeys = paragraphs(input).map(parse_monkey).collect_vec();
     let s = monkey_stuff(monkeys, 10000, false);
     Some(s)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 11);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 11);
+        let input = crate::read_file("examples", 2022, 11);
         assert_eq!(part_one(&input), Some(10605));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 11);
+        let input = crate::read_file("examples", 2022, 11);
         assert_eq!(part_two(&input), Some(2713310158));
     }
 }
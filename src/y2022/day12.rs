@@ -0,0 +1,71 @@
+use crate::helpers::grid::Grid;
+use crate::helpers::search::bfs;
+use rusttype::Point;
+
+type P = Point<i32>;
+
+/// The fewest steps from `start_position` to a position satisfying `exit_condition`,
+/// stepping only where `check_height(next, current)` allows.
+fn solve_map(
+    start_position: P,
+    map: &Grid<i32>,
+    check_height: impl Fn(i32, i32) -> bool,
+    exit_condition: impl Fn(&P) -> bool,
+) -> Option<u32> {
+    let successors = |&position: &P| {
+        map.neighbours(position)
+            .filter(|&next| check_height(map[next], map[position]))
+            .collect::<Vec<_>>()
+    };
+    bfs([start_position], successors, exit_condition).map(|found| found.cost as u32)
+}
+
+/// Returns the heights of the map, the start position and the end position.
+fn get_map(input: &str) -> (Grid<i32>, P, P) {
+    let map: Grid<char> = input.trim().parse().unwrap();
+    let start_position = map.position(|&c| c == 'S').unwrap();
+    let end_position = map.position(|&c| c == 'E').unwrap();
+    let heights = map.map(|&c| match c {
+        'S' => 0,
+        'E' => 'z' as i32 - 'a' as i32,
+        c => c as i32 - 'a' as i32,
+    });
+    (heights, start_position, end_position)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let (map, start_position, end_position) = get_map(input);
+    solve_map(
+        start_position,
+        &map,
+        |next, current| next - current <= 1,
+        |p| *p == end_position,
+    )
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let (map, _, end_position) = get_map(input);
+    solve_map(
+        end_position,
+        &map,
+        |next, current| current - next <= 1,
+        |p| map[*p] == 0,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 12);
+        assert_eq!(part_one(&input), Some(31));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 12);
+        assert_eq!(part_two(&input), Some(29));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::helpers::parse::paragraphs;
 use itertools::{all, Itertools};
 use std::cmp::Ordering;
 
@@ -54,9 +55,7 @@ impl PartialEq for Signal {
     }
 }
 
-impl Eq for Signal {
-    fn assert_receiver_is_total_eq(&self) {}
-}
+impl Eq for Signal {}
 
 fn parse_signal(input: &mut std::iter::Peekable<std::str::Chars>) -> Signal {
     let mut signal: Vec<Signal> = Vec::new();
@@ -98,7 +97,7 @@ fn parse_signal(input: &mut std::iter::Peekable<std::str::Chars>) -> Signal {
 
 pub fn part_one(input: &str) -> Option<u32> {
     let mut numbers: Vec<u32> = Vec::new();
-    for (i, pairs) in input.trim().split("\n\n").enumerate() {
+    for (i, pairs) in paragraphs(input).enumerate() {
         let (n1, n2) = pairs.split_once('\n').unwrap();
         let s1: Signal = parse_signal(&mut n1.chars().peekable());
         let s2: Signal = parse_signal(&mut n2.chars().peekable());
@@ -128,19 +127,13 @@ pub fn part_two(input: &str) -> Option<usize> {
     Some(i1 * i2)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 13);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 13);
+        let input = crate::read_file("examples", 2022, 13);
         assert_eq!(part_one(&input), Some(13));
     }
 
@@ -184,7 +177,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 13);
+        let input = crate::read_file("examples", 2022, 13);
         assert_eq!(part_two(&input), Some(140));
     }
 }
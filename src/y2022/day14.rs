@@ -0,0 +1,108 @@
+use crate::helpers::grid::SparseGrid;
+use itertools::Itertools;
+use rusttype::{Point, Vector};
+
+fn parse_map(input: &str) -> SparseGrid<char> {
+    let mut map = SparseGrid::new();
+    for line in input.trim().lines() {
+        let coords = line.split("->").map(|coords| {
+            let (x, y) = coords.trim().split_once(',').unwrap();
+            (x.parse::<i32>().unwrap(), y.parse::<i32>().unwrap())
+        });
+
+        for ((x1, y1), (x2, y2)) in coords.tuple_windows() {
+            if x1 == x2 {
+                for y in y1.min(y2)..=y1.max(y2) {
+                    map.insert(Point { x: x1, y }, '#');
+                }
+            } else {
+                for x in x1.min(x2)..=x1.max(x2) {
+                    map.insert(Point { x, y: y1 }, '#');
+                }
+            }
+        }
+    }
+    map
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let mut map = parse_map(input);
+    let (_, map_max) = map.bounds().unwrap();
+
+    let start_p: Point<i32> = Point { x: 500, y: 0 };
+    let t = [
+        Vector { x: 0, y: 1 },
+        Vector { x: -1, y: 1 },
+        Vector { x: 1, y: 1 },
+    ];
+
+    for counter in 0.. {
+        let mut p: Point<i32> = start_p;
+
+        loop {
+            if let Some(v) = t.iter().find(|v| !map.contains(p + **v)) {
+                p = p + *v;
+            } else {
+                map.insert(p, 'o');
+                break;
+            }
+
+            if p.y > map_max.y {
+                return Some(counter);
+            }
+        }
+    }
+    None
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let mut map = parse_map(input);
+    let (_, map_max) = map.bounds().unwrap();
+
+    let start_p: Point<i32> = Point { x: 500, y: 0 };
+    let t = [
+        Vector { x: 0, y: 1 },
+        Vector { x: -1, y: 1 },
+        Vector { x: 1, y: 1 },
+    ];
+
+    for counter in 0.. {
+        let mut p: Point<i32> = start_p;
+
+        if map.contains(start_p) {
+            return Some(counter);
+        }
+
+        loop {
+            if let Some(v) = t.iter().find(|v| !map.contains(p + **v)) {
+                p = p + *v;
+            } else {
+                map.insert(p, 'o');
+                break;
+            }
+
+            if p.y == map_max.y + 1 {
+                map.insert(p, 'o');
+                break;
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 14);
+        assert_eq!(part_one(&input), Some(24));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 14);
+        assert_eq!(part_two(&input), Some(93));
+    }
+}
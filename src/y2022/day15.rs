@@ -0,0 +1,114 @@
+use crate::helpers::interval::IntervalSet;
+use crate::helpers::manhattan::Diamond;
+use crate::helpers::parse::{extract, parse_lines, LineError};
+use itertools::Itertools;
+use rusttype::Point;
+
+struct Sensor {
+    area: Diamond,
+    beacon: Point<i32>,
+}
+
+/// The columns of `row` that are no further from a sensor than its beacon.
+fn coverage(sensors: &[Sensor], row: i32) -> IntervalSet<i32> {
+    sensors.iter().map(|s| s.area.row(row)).collect()
+}
+
+fn parse_sensors(input: &str) -> Vec<Sensor> {
+    parse_lines(input.trim(), |line| {
+        let (x1, y1, x2, y2) = extract(
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            line,
+        )?;
+        let beacon = Point { x: x2, y: y2 };
+        Ok::<_, LineError>(Sensor {
+            area: Diamond::through(Point { x: x1, y: y1 }, beacon),
+            beacon,
+        })
+    })
+    .unwrap()
+}
+
+fn count_covered(input: &str, row: i32) -> Option<u32> {
+    let sensors = parse_sensors(input);
+    let covered = coverage(&sensors, row);
+
+    let beacons = sensors
+        .iter()
+        .map(|s| s.beacon)
+        .filter(|b| b.y == row && covered.contains(b.x))
+        .unique()
+        .count();
+
+    Some(covered.len() as u32 - beacons as u32)
+}
+
+/// The only free position is surrounded by the areas of sensors, so it lies just outside of
+/// at least two of them, where their edges cross. On the border of the search area, it may
+/// instead lie where an edge crosses the border, or in one of its corners.
+fn find_beacon(input: &str, size: i32) -> Option<u64> {
+    let sensors = parse_sensors(input);
+    let outlines = sensors.iter().map(|s| s.area.grow(1)).collect_vec();
+    let in_bounds = |p: &Point<i32>| (0..=size).contains(&p.x) && (0..=size).contains(&p.y);
+
+    let crossings = outlines
+        .iter()
+        .tuple_combinations()
+        .flat_map(|(a, b)| a.edge_intersections(b));
+    let border_crossings = outlines.iter().flat_map(|outline| {
+        [0, size].into_iter().flat_map(move |border| {
+            outline
+                .perimeter_in_row(border)
+                .into_iter()
+                .chain(outline.perimeter_in_column(border))
+        })
+    });
+    let corners = [0, size]
+        .into_iter()
+        .cartesian_product([0, size])
+        .map(|(x, y)| Point { x, y });
+
+    crossings
+        .chain(border_crossings)
+        .chain(corners)
+        .filter(in_bounds)
+        .find(|&p| sensors.iter().all(|s| !s.area.contains(p)))
+        .map(|p| 4_000_000 * p.x as u64 + p.y as u64)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    count_covered(input, 2_000_000)
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    find_beacon(input, 4_000_000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 15);
+        assert_eq!(count_covered(&input, 10), Some(26));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 15);
+        assert_eq!(find_beacon(&input, 20), Some(56000011));
+    }
+
+    #[test]
+    fn test_find_beacon_on_border() {
+        // (0, 2) lies just outside of two sensors whose edges are parallel there.
+        let input = "\
+Sensor at x=2, y=-1: closest beacon is at x=0, y=1
+Sensor at x=-2, y=3: closest beacon is at x=0, y=3
+Sensor at x=4, y=4: closest beacon is at x=0, y=4
+Sensor at x=4, y=0: closest beacon is at x=4, y=3
+";
+        assert_eq!(find_beacon(input, 4), Some(2));
+    }
+}